@@ -1,4 +1,6 @@
+use std::hash::{Hash, Hasher};
 use std::num::NonZeroU16;
+use std::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not};
 use std::str::FromStr;
 
 use bitvec::access::BitSafeU64;
@@ -11,9 +13,9 @@ use crate::array::delta::ArrayDelta;
 use crate::err::{OutOfBounds, PatternError};
 use crate::ext::{FoldMut, NotWhitespace, assert_then, safe_into};
 use crate::num::{Point, Rect, SignedMag, Size};
-use crate::{ArrayIndex, ArrayPoint, ArrayRect, ArrayVector, GridView, GridViewMut};
+use crate::{Adjacency, ArrayIndex, ArrayPoint, ArrayRect, ArrayVector, GridMask, GridVector, GridView, GridViewMut};
 
-use super::{Cells, GridGetIndex, GridGetMutIndex, GridSetIndex, Points, Spaces};
+use super::{Cells, ComponentIter, GridGetIndex, GridGetMutIndex, GridSetIndex, Points, Spaces};
 
 /// A fixed-size bit grid with `W` columns and `H` rows.
 #[derive(Debug, Clone, PartialEq, Eq, derive_more::From, derive_more::Into)]
@@ -55,6 +57,135 @@ impl<const W: u16, const H: u16, const WORDS: usize> ArrayGrid<W, H, WORDS> {
         )
     };
 
+    /// Creates a new grid where each cell is set if `f(x, y)` returns `true`.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - A predicate taking the `x` and `y` coordinates of a cell.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use grid_mask::{ArrayGrid, ArrayPoint};
+    /// let diagonal = ArrayGrid::<4, 4, 1>::from_fn(|x, y| x == y);
+    ///
+    /// assert_eq!(diagonal.count(), 4);
+    /// assert!(diagonal.get(ArrayPoint::const_new::<2, 2>()));
+    /// assert!(!diagonal.get(ArrayPoint::const_new::<2, 1>()));
+    /// ```
+    ///
+    /// Invalid grid dimensions fail at compile time:
+    ///
+    /// ```rust,compile_fail
+    /// # use grid_mask::ArrayGrid;
+    /// let grid = ArrayGrid::<4, 4, 2>::from_fn(|x, y| x == y);
+    /// ```
+    ///
+    /// ```rust,compile_fail
+    /// # use grid_mask::ArrayGrid;
+    /// let grid = ArrayGrid::<0, 4, 1>::from_fn(|x, y| x == y);
+    /// ```
+    #[must_use]
+    pub fn from_fn(f: impl Fn(u16, u16) -> bool) -> Self {
+        // referencing the word count forces its compile time check
+        let _: usize = Self::WORD_COUNT;
+
+        let mut grid = Self::EMPTY;
+        grid.fill_from_fn(f);
+        grid
+    }
+
+    /// Combines `self` and `other` cell by cell, setting each cell to `f(self_cell, other_cell)`.
+    ///
+    /// Prefer the bitwise operators for `&`, `|`, and `^`, which operate on whole words.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The grid to combine with.
+    /// * `f` - A function taking the value of a cell in `self` and in `other`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use grid_mask::array_grid;
+    /// type Grid = array_grid!(10, 10);
+    ///
+    /// let lower = Grid::from_fn(|x, y| x <= y);
+    /// let diagonal = Grid::from_fn(|x, y| x == y);
+    ///
+    /// let difference = lower.zip(&diagonal, |a, b| a && !b);
+    /// assert_eq!(difference, Grid::from_fn(|x, y| x < y));
+    /// ```
+    #[must_use]
+    pub fn zip(&self, other: &Self, f: impl Fn(bool, bool) -> bool) -> Self {
+        let mut grid = Self::EMPTY;
+        let pairs = std::iter::zip(self.bits().iter().by_vals(), other.bits().iter().by_vals());
+        std::iter::zip(grid.bits_mut().iter_mut(), pairs).for_each(|(mut cell, (a, b))| cell.set(f(a, b)));
+        grid
+    }
+
+    /// Parses a string pattern into an [`ArrayGrid`], using custom characters for set and
+    /// unset cells.
+    ///
+    /// Whitespace is ignored. The [`FromStr`] implementation is equivalent to calling this
+    /// with `#` and `.`.
+    ///
+    /// # Arguments
+    ///
+    /// * `pattern` - The pattern to parse, containing exactly `W * H` non-whitespace
+    ///   characters.
+    /// * `set` - The character marking set cells.
+    /// * `unset` - The character marking unset cells.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// * The pattern contains characters other than `set`, `unset`, or whitespace.
+    /// * The pattern contains too many or too few valid characters.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `set` and `unset` are equal, or if either is whitespace.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use grid_mask::{ArrayPoint, array_grid};
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let grid = <array_grid!(5, 2)>::from_pattern("
+    ///     X _ _ _ _
+    ///     _ _ _ _ X
+    /// ", 'X', '_')?;
+    ///
+    /// assert_eq!(grid, <array_grid!(5, 2)>::from_iter([ArrayPoint::ORIGIN, ArrayPoint::MAX]));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_pattern(pattern: &str, set: char, unset: char) -> Result<Self, PatternError> {
+        assert!(set != unset, "set and unset must be different characters");
+        assert!(set.is_not_whitespace() && unset.is_not_whitespace(), "set and unset must not be whitespace");
+
+        pattern
+            .chars()
+            .filter(NotWhitespace::is_not_whitespace)
+            .take(Self::CELLS_USZ + 1)
+            .enumerate()
+            .map(|(i, c)| (ArrayIndex::try_new(i), c))
+            .try_fold((Self::EMPTY, None), |(mut grid, _), (i, c)| match (i, c) {
+                (Err(_), _) => Err(PatternError::TooLong),
+                (Ok(i), c) if c == set => {
+                    grid.set(i, true);
+                    (grid, Some(i)).into_ok()
+                }
+                (Ok(i), c) if c == unset => (grid, Some(i)).into_ok(),
+                (_, c) => PatternError::InvalidChar(c).into_err(),
+            })
+            .and_then(|(grid, index)| match index.map_or(0, |i| i.get() + 1) {
+                i if i == Self::CELLS => Ok(grid),
+                i => PatternError::TooShort(i).into_err(),
+            })
+    }
+
     /// Gets the cell value(s) identified by `index`.
     ///
     /// The behavior and return type of this method depend on the type of `IDX`.
@@ -196,6 +327,170 @@ impl<const W: u16, const H: u16, const WORDS: usize> ArrayGrid<W, H, WORDS> {
         safe_into!(self.data.count_ones() => u32)
     }
 
+    /// Returns the number of unset cells in the grid.
+    #[must_use]
+    pub fn count_zeros(&self) -> u32 {
+        Self::CELLS - self.count()
+    }
+
+    /// Returns `true` if every set cell in `self` is also set in `other`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use grid_mask::array_grid;
+    /// type Grid = array_grid!(10, 10);
+    ///
+    /// let diagonal = Grid::from_fn(|x, y| x == y);
+    /// assert!(diagonal.is_subset_of(&Grid::FULL));
+    /// assert!(Grid::EMPTY.is_subset_of(&diagonal));
+    /// assert!(!Grid::FULL.is_subset_of(&diagonal));
+    /// ```
+    #[must_use]
+    pub fn is_subset_of(&self, other: &Self) -> bool {
+        std::iter::zip(&self.data.data, &other.data.data).all(|(word, other)| word & !other == 0)
+    }
+
+    /// Returns `true` if `self` and `other` share at least one set cell.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use grid_mask::array_grid;
+    /// type Grid = array_grid!(10, 10);
+    ///
+    /// let diagonal = Grid::from_fn(|x, y| x == y);
+    /// assert!(diagonal.overlaps(&Grid::FULL));
+    /// assert!(!diagonal.overlaps(&!diagonal.clone()));
+    /// assert!(!Grid::EMPTY.overlaps(&Grid::EMPTY));
+    /// ```
+    #[must_use]
+    pub fn overlaps(&self, other: &Self) -> bool {
+        std::iter::zip(&self.data.data, &other.data.data).any(|(word, other)| word & other != 0)
+    }
+
+    /// Returns the number of cells that differ between `self` and `other`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use grid_mask::array_grid;
+    /// type Grid = array_grid!(10, 10);
+    ///
+    /// let diagonal = Grid::from_fn(|x, y| x == y);
+    /// assert_eq!(diagonal.hamming_distance(&Grid::EMPTY), 10);
+    /// assert_eq!(diagonal.hamming_distance(&Grid::FULL), 90);
+    /// assert_eq!(diagonal.hamming_distance(&diagonal), 0);
+    /// ```
+    #[must_use]
+    pub fn hamming_distance(&self, other: &Self) -> u32 {
+        std::iter::zip(&self.data.data, &other.data.data).map(|(word, other)| (word ^ other).count_ones()).sum()
+    }
+
+    /// Returns the number of set cells in row `y`.
+    ///
+    /// Returns `0` if `y` is out of bounds.
+    ///
+    /// # Arguments
+    ///
+    /// * `y` - The row to count.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use grid_mask::array_grid;
+    /// let grid = <array_grid!(10, 3)>::from_fn(|x, y| x <= y);
+    ///
+    /// assert_eq!(grid.count_row(2), 3);
+    /// assert_eq!(<array_grid!(10, 3)>::FULL.count_row(0), 10);
+    /// assert_eq!(grid.count_row(3), 0);
+    /// ```
+    #[must_use]
+    pub fn count_row(&self, y: u16) -> u32 {
+        self.bits().chunks_exact(Self::W_USIZE).nth(usize::from(y)).map_or(0, |row| safe_into!(row.count_ones() => u32))
+    }
+
+    /// Returns the number of set cells in column `x`.
+    ///
+    /// Returns `0` if `x` is out of bounds.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - The column to count.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use grid_mask::array_grid;
+    /// let grid = <array_grid!(10, 3)>::from_fn(|x, y| x <= y);
+    ///
+    /// assert_eq!(grid.count_col(0), 3);
+    /// assert_eq!(<array_grid!(10, 3)>::FULL.count_col(9), 3);
+    /// assert_eq!(grid.count_col(10), 0);
+    /// ```
+    #[must_use]
+    pub fn count_col(&self, x: u16) -> u32 {
+        match x < W {
+            true => self.bits().chunks_exact(Self::W_USIZE).map(|row| u32::from(row[usize::from(x)])).sum(),
+            false => 0,
+        }
+    }
+
+    /// Returns the tightest rectangle containing all set cells, or [`None`] if the grid is
+    /// empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use grid_mask::{ArrayPoint, ArrayRect, array_grid};
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let grid = <array_grid!(10, 4)>::from_iter([ArrayPoint::new(2, 1)?, ArrayPoint::new(7, 2)?]);
+    ///
+    /// assert_eq!(grid.bounds(), Some(ArrayRect::new((2, 1), (6, 2))?));
+    /// assert_eq!(<array_grid!(10, 4)>::EMPTY.bounds(), None);
+    /// assert_eq!(<array_grid!(10, 4)>::FULL.bounds(), Some(ArrayRect::const_new::<0, 0, 10, 4>()));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn bounds(&self) -> Option<ArrayRect<W, H>> {
+        let y_min = self.bits().first_one()? / Self::W_USIZE;
+        let y_max = self.bits().last_one()? / Self::W_USIZE;
+
+        let x_min = self.row_iter().filter_map(BitSlice::first_one).min()?;
+        let x_max = self.row_iter().filter_map(BitSlice::last_one).max()?;
+
+        ArrayRect::new((x_min, y_min), (x_max - x_min + 1, y_max - y_min + 1)).ok()
+    }
+
+    /// Returns a [`Display`](std::fmt::Display) implementation that visualizes the grid.
+    ///
+    /// Each of the `H` rows is written as `W` characters, followed by a newline.
+    ///
+    /// # Arguments
+    ///
+    /// * `set` - The character to use for set cells.
+    /// * `unset` - The character to use for unset cells.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use grid_mask::array_grid;
+    /// let grid = <array_grid!(5, 2)>::from_fn(|x, y| x == y);
+    ///
+    /// assert_eq!(grid.visualize('#', '.').to_string(), "#....\n.#...\n");
+    /// ```
+    #[must_use]
+    pub fn visualize(&self, set: char, unset: char) -> impl std::fmt::Display + '_ {
+        let map_char = move |is_set: bool| if is_set { set } else { unset };
+        std::fmt::from_fn(move |f| {
+            self.row_iter().try_for_each(|row| {
+                row.iter().by_vals().map(map_char).try_for_each(|c| write!(f, "{c}"))?;
+                writeln!(f)
+            })
+        })
+    }
+
     /// Returns the raw data.
     #[must_use]
     pub const fn data(&self) -> &[u64] {
@@ -214,6 +509,40 @@ impl<const W: u16, const H: u16, const WORDS: usize> ArrayGrid<W, H, WORDS> {
         &mut self.data[..Self::CELLS_USZ]
     }
 
+    /// Returns an iterator over the rows of the grid, from top to bottom.
+    ///
+    /// Each row is a bit slice of length `W`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use grid_mask::array_grid;
+    /// let grid = <array_grid!(10, 4)>::from_fn(|x, y| y % 2 == 0 || x == 0);
+    /// let complete: Vec<_> = grid.row_iter().enumerate().filter(|(_, row)| row.all()).map(|(y, _)| y).collect();
+    ///
+    /// assert_eq!(complete, [0, 2]);
+    /// ```
+    pub fn row_iter(&self) -> impl Iterator<Item = &BitSlice<u64, Lsb0>> {
+        self.bits().chunks_exact(Self::W_USIZE)
+    }
+
+    /// Returns an iterator over the mutable rows of the grid, from top to bottom.
+    ///
+    /// Each row is a bit slice of length `W`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use grid_mask::array_grid;
+    /// let mut grid = <array_grid!(10, 4)>::EMPTY;
+    /// grid.row_iter_mut().step_by(2).for_each(|row| row.fill(true));
+    ///
+    /// assert_eq!(grid, <array_grid!(10, 4)>::from_fn(|_, y| y % 2 == 0));
+    /// ```
+    pub fn row_iter_mut(&mut self) -> impl Iterator<Item = &mut BitSlice<BitSafeU64, Lsb0>> {
+        self.bits_mut().chunks_exact_mut(Self::W_USIZE)
+    }
+
     /// Returns an iterator over all cells in the grid.
     #[must_use]
     pub const fn cells(&self) -> Cells<'_, W, H, WORDS> {
@@ -329,6 +658,72 @@ impl<const W: u16, const H: u16, const WORDS: usize> ArrayGrid<W, H, WORDS> {
         }
     }
 
+    /// Flips the cell at `point`.
+    ///
+    /// # Arguments
+    ///
+    /// * `point` - The point of the cell to flip.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use grid_mask::{ArrayPoint, array_grid};
+    /// let mut grid = <array_grid!(10, 10)>::EMPTY;
+    ///
+    /// grid.toggle(ArrayPoint::MAX);
+    /// assert!(grid.get(ArrayPoint::MAX));
+    ///
+    /// grid.toggle(ArrayPoint::MAX);
+    /// assert!(!grid.get(ArrayPoint::MAX));
+    /// ```
+    pub const fn toggle(&mut self, point: ArrayPoint<W, H>) {
+        self.toggle_index(ArrayIndex::from_point(point));
+    }
+
+    /// Flips the cell at `index`.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The index of the cell to flip.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use grid_mask::{ArrayIndex, array_grid};
+    /// let mut grid = <array_grid!(10, 10)>::FULL;
+    ///
+    /// grid.toggle_index(ArrayIndex::MIN);
+    /// assert!(!grid.get(ArrayIndex::MIN));
+    /// assert_eq!(grid.count(), 99);
+    /// ```
+    pub const fn toggle_index(&mut self, index: ArrayIndex<W, H>) {
+        let (word, bit) = index.word_and_bit();
+        self.data.data[word] ^= 1u64 << bit;
+    }
+
+    /// Sets all cells within `rect` to `value`.
+    ///
+    /// # Arguments
+    ///
+    /// * `rect` - The region of cells to set.
+    /// * `value` - The new state of the cells.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use grid_mask::{ArrayRect, array_grid};
+    /// let mut grid = <array_grid!(10, 10)>::EMPTY;
+    ///
+    /// grid.set_region(ArrayRect::const_new::<2, 3, 4, 5>(), true);
+    /// assert_eq!(grid.count(), 20);
+    ///
+    /// grid.set_region(ArrayRect::const_new::<0, 0, 10, 10>(), true);
+    /// assert_eq!(grid, <array_grid!(10, 10)>::FULL);
+    /// ```
+    pub fn set_region(&mut self, rect: ArrayRect<W, H>, value: bool) {
+        self.mut_view_at(rect).fill(value);
+    }
+
     /// Clears all cells in the grid.
     pub fn clear(&mut self) {
         self.fill(false);
@@ -339,6 +734,28 @@ impl<const W: u16, const H: u16, const WORDS: usize> ArrayGrid<W, H, WORDS> {
         self.data[..Self::CELLS_USZ].fill(value);
     }
 
+    /// Sets each cell in the grid to the result of `f(x, y)`, in row-major order.
+    ///
+    /// This is the in-place counterpart of [`from_fn`](Self::from_fn).
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - A predicate taking the `x` and `y` coordinates of a cell.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use grid_mask::array_grid;
+    /// let mut grid = <array_grid!(10, 10)>::FULL;
+    ///
+    /// grid.fill_from_fn(|x, y| x == y);
+    /// assert_eq!(grid, <array_grid!(10, 10)>::from_fn(|x, y| x == y));
+    /// ```
+    pub fn fill_from_fn(&mut self, f: impl Fn(u16, u16) -> bool) {
+        let coords = (0..H).flat_map(|y| (0..W).map(move |x| (x, y)));
+        std::iter::zip(self.bits_mut().iter_mut(), coords).for_each(|(mut cell, (x, y))| cell.set(f(x, y)));
+    }
+
     /// Translates the grid by the given displacement vector.
     ///
     /// Bits that shift beyond the grid boundary are discarded; vacated
@@ -360,43 +777,339 @@ impl<const W: u16, const H: u16, const WORDS: usize> ArrayGrid<W, H, WORDS> {
         }
     }
 
-    fn bitwise_op_at<'a>(
-        &mut self,
-        other: impl Into<GridView<'a>>,
-        at: ArrayPoint<W, H>,
-        op: impl Fn(&mut BitSlice<BitSafeU64, Lsb0>, &BitSlice<u64, Lsb0>) + Copy,
-    ) -> Result<(), OutOfBounds> {
-        let other = other.into();
-        let mut view = ArrayRect::new(at, other.size()).map(|rect| self.get_mut(rect))?;
-
-        std::iter::zip(view.rows_mut(), other.rows()).for_each(|(dst_row, src_row)| op(dst_row, src_row));
-
-        Ok(())
-    }
-
-    /// Performs a logical AND operation with another grid `other` at `at`.
+    /// Translates the grid by the given displacement vector, wrapping toroidally.
     ///
-    /// Only points in the intersection of the two grids are affected.
+    /// Cells that shift past the right edge reappear on the left edge of the same row, and
+    /// cells that shift past the bottom edge reappear on the top edge, and vice versa.
     ///
-    /// # Errors
+    /// # Arguments
     ///
-    /// [`OutOfBounds`] if the `other` grid does not fit within `self` at `at`.
-    pub fn bitand_at<'a>(&mut self, other: impl Into<GridView<'a>>, at: ArrayPoint<W, H>) -> Result<(), OutOfBounds> {
-        self.bitwise_op_at(other, at, |dst, src| *dst &= src)
-    }
-
-    /// Performs a logical OR operation with another grid at the specified point.
+    /// * `vec` - The vector to translate the grid by.
     ///
-    /// Only points in the intersection of the two grids are affected.
+    /// # Examples
     ///
-    /// # Errors
+    /// ```rust
+    /// # use grid_mask::{ArrayPoint, ArrayVector, array_grid};
+    /// let mut grid = <array_grid!(10, 10)>::EMPTY;
+    /// grid.set(ArrayPoint::new(9, 3)?, true);
     ///
-    /// [`OutOfBounds`] if the `other` grid does not fit within `self` at `at`.
-    pub fn bitor_at<'a>(&mut self, other: impl Into<GridView<'a>>, at: ArrayPoint<W, H>) -> Result<(), OutOfBounds> {
-        self.bitwise_op_at(other, at, |dst, src| *dst |= src)
+    /// grid.translate_wrapping(ArrayVector::EAST);
+    /// assert!(grid.get(ArrayPoint::new(0, 3)?));
+    ///
+    /// grid.translate_wrapping(ArrayVector::new(-1, -4));
+    /// assert!(grid.get(ArrayPoint::new(9, 9)?));
+    /// assert_eq!(grid.count(), 1);
+    /// # Ok::<(), grid_mask::err::OutOfBounds>(())
+    /// ```
+    pub fn translate_wrapping(&mut self, vec: ArrayVector) {
+        let dx = safe_into!(vec.dx.rem_euclid(i32::from(W)) => usize);
+        let dy = safe_into!(vec.dy.rem_euclid(i32::from(H)) => usize);
+
+        self.bits_mut().rotate_right(dy * Self::W_USIZE);
+        self.bits_mut().chunks_exact_mut(Self::W_USIZE).for_each(|row| row.rotate_right(dx));
     }
 
-    /// Performs a logical XOR operation with another grid at the specified point.
+    /// Returns a copy of the grid translated by the given displacement vector.
+    ///
+    /// This is the non-mutating form of [`ArrayGrid::translate`]. Bits that shift beyond
+    /// the grid boundary are discarded; vacated positions are filled with `false`.
+    ///
+    /// # Arguments
+    ///
+    /// * `vec` - The vector to translate the grid by.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use grid_mask::{ArrayVector, array_grid};
+    /// let grid = <array_grid!(10, 10)>::FULL;
+    /// let translated = grid.translated(ArrayVector::EAST);
+    ///
+    /// assert_eq!(translated.count(), 90);
+    /// assert!(!translated.get((0u16, 5u16))?);
+    /// # Ok::<(), grid_mask::err::OutOfBounds>(())
+    /// ```
+    #[must_use]
+    pub fn translated(&self, vec: ArrayVector) -> Self {
+        let mut grid = self.clone();
+        grid.translate(vec);
+        grid
+    }
+
+    /// Returns a grid grown to include all cells adjacent to the set cells, using the
+    /// provided [`Adjacency`].
+    ///
+    /// Growth is clipped at the edges of the grid, and never wraps between rows.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `A` - The [`Adjacency`] strategy to use.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::str::FromStr;
+    /// # use grid_mask::{Cardinal, array_grid};
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let grid = <array_grid!(5, 3)>::from_str("
+    ///     . . . . #
+    ///     . . . . .
+    ///     . . . . .
+    /// ")?;
+    ///
+    /// assert_eq!(grid.grow::<Cardinal>(), <array_grid!(5, 3)>::from_str("
+    ///     . . . # #
+    ///     . . . . #
+    ///     . . . . .
+    /// ")?);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn grow<A: Adjacency>(&self) -> Self {
+        let vertical = A::NEIGHBORS
+            .iter()
+            .filter(|vec| vec.x == 0)
+            .fold_mut(self.clone(), |acc, &vec| *acc |= self.translated(ArrayVector::from(vec)));
+
+        // with diagonal neighbors, spreading the vertically grown grid sideways also covers
+        // the diagonals, so each axis only needs its own two translations
+        let source = match A::NEIGHBORS.iter().any(GridVector::is_diagonal) {
+            true => &vertical,
+            false => self,
+        };
+
+        A::NEIGHBORS
+            .iter()
+            .filter(|vec| vec.y == 0)
+            .fold_mut(vertical.clone(), |acc, &vec| *acc |= source.translated(ArrayVector::from(vec)))
+    }
+
+    /// Returns a grid of all cells connected to `seed` within the current grid, using the
+    /// provided [`Adjacency`].
+    ///
+    /// Returns [`ArrayGrid::EMPTY`] if `seed` is not set.
+    ///
+    /// # Arguments
+    ///
+    /// * `seed` - The starting point for the flood fill.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `A` - The [`Adjacency`] strategy to use.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::str::FromStr;
+    /// # use grid_mask::{ArrayPoint, Cardinal, array_grid};
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let grid = <array_grid!(5, 3)>::from_str("
+    ///     . # # . #
+    ///     . . # . #
+    ///     . # # . .
+    /// ")?;
+    ///
+    /// let seed = ArrayPoint::const_new::<1, 0>();
+    /// assert_eq!(grid.connected::<Cardinal>(seed), <array_grid!(5, 3)>::from_str("
+    ///     . # # . .
+    ///     . . # . .
+    ///     . # # . .
+    /// ")?);
+    /// assert_eq!(grid.connected::<Cardinal>(ArrayPoint::MAX), <array_grid!(5, 3)>::EMPTY);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn connected<A: Adjacency>(&self, seed: ArrayPoint<W, H>) -> Self {
+        match self.get(seed) {
+            false => Self::EMPTY,
+            true => {
+                let mut connected = Self::from_iter([seed]);
+                loop {
+                    match connected.grow::<A>() & self {
+                        grown if grown == connected => break connected,
+                        grown => connected = grown,
+                    }
+                }
+            }
+        }
+    }
+
+    /// Returns an iterator over the connected components of the grid, using the
+    /// provided [`Adjacency`].
+    ///
+    /// Each component is yielded as a separate [`ArrayGrid`]. Components are disjoint,
+    /// and together they cover every set cell of the grid. Components are yielded in
+    /// order of their first cell.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `A` - The [`Adjacency`] strategy to use.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use grid_mask::{ArrayPoint, Cardinal, array_grid};
+    /// type Grid = array_grid!(10, 10);
+    ///
+    /// let grid = Grid::from_iter([ArrayPoint::ORIGIN, ArrayPoint::MAX]);
+    /// let components: Vec<Grid> = grid.components::<Cardinal>().collect();
+    ///
+    /// assert_eq!(components, [Grid::from_iter([ArrayPoint::ORIGIN]), Grid::from_iter([ArrayPoint::MAX])]);
+    /// ```
+    #[must_use]
+    pub fn components<A: Adjacency>(&self) -> ComponentIter<A, W, H, WORDS> {
+        ComponentIter::new(self.clone())
+    }
+
+    /// Returns the number of connected components of the grid, using the provided
+    /// [`Adjacency`].
+    ///
+    /// # Type Parameters
+    ///
+    /// * `A` - The [`Adjacency`] strategy to use.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use grid_mask::{Cardinal, Octile, array_grid};
+    /// let checkerboard = <array_grid!(10, 10)>::from_fn(|x, y| (x + y) % 2 == 0);
+    ///
+    /// assert_eq!(<array_grid!(10, 10)>::EMPTY.count_components::<Cardinal>(), 0);
+    /// assert_eq!(checkerboard.count_components::<Cardinal>(), 50);
+    /// assert_eq!(checkerboard.count_components::<Octile>(), 1);
+    /// ```
+    #[must_use]
+    pub fn count_components<A: Adjacency>(&self) -> usize {
+        self.components::<A>().count()
+    }
+
+    /// Returns a grid of all cells whose neighbors, under the provided [`Adjacency`], are
+    /// all set in the current grid.
+    ///
+    /// This is the dual of [`grow`](Self::grow). Cells outside the grid are treated as
+    /// unset, so cells on the edge of the grid never survive erosion.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `A` - The [`Adjacency`] strategy to use.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::str::FromStr;
+    /// # use grid_mask::{Cardinal, array_grid};
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let grid = <array_grid!(5, 3)>::FULL;
+    ///
+    /// assert_eq!(grid.erode::<Cardinal>(), <array_grid!(5, 3)>::from_str("
+    ///     . . . . .
+    ///     . # # # .
+    ///     . . . . .
+    /// ")?);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn erode<A: Adjacency>(&self) -> Self {
+        !(!self.clone()).grow::<A>() & &Self::INTERIOR
+    }
+
+    /// Returns the next generation of a cellular automaton.
+    ///
+    /// Each cell in the result is set to `rule(cell, neighbors)`, where `cell` is its current
+    /// value and `neighbors` is the number of its set neighbors under the provided
+    /// [`Adjacency`]. Cells outside the grid are treated as unset.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `A` - The [`Adjacency`] strategy to use.
+    ///
+    /// # Arguments
+    ///
+    /// * `rule` - A function taking the current value of a cell and its neighbor count.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use grid_mask::{Octile, array_grid};
+    /// type Grid = array_grid!(5, 5);
+    ///
+    /// let life = |cell: bool, n: u8| (cell && (n == 2 || n == 3)) || (!cell && n == 3);
+    /// let blinker = Grid::from_fn(|x, y| y == 2 && (1..=3).contains(&x));
+    ///
+    /// let next = blinker.cellular_automaton_step::<Octile>(life);
+    /// assert_eq!(next, Grid::from_fn(|x, y| x == 2 && (1..=3).contains(&y)));
+    /// assert_eq!(next.cellular_automaton_step::<Octile>(life), blinker);
+    /// ```
+    #[must_use]
+    pub fn cellular_automaton_step<A: Adjacency>(&self, rule: impl Fn(bool, u8) -> bool) -> Self {
+        let mut counts = vec![0u8; Self::CELLS_USZ];
+        A::NEIGHBORS.iter().map(|&vec| self.translated(ArrayVector::from(vec))).for_each(|neighbors| {
+            neighbors.bits().iter_ones().for_each(|index| counts[index] += 1);
+        });
+
+        let mut grid = Self::EMPTY;
+        let cells = std::iter::zip(self.bits().iter().by_vals(), counts);
+        std::iter::zip(grid.bits_mut().iter_mut(), cells)
+            .for_each(|(mut next, (cell, count))| next.set(rule(cell, count)));
+        grid
+    }
+
+    /// A grid with every cell not on the edge of the grid set.
+    const INTERIOR: Self = const {
+        let mut grid = Self::EMPTY;
+        let mut y = 1;
+        while y + 1 < Self::H_USIZE {
+            let mut x = 1;
+            while x + 1 < Self::W_USIZE {
+                let index = y * Self::W_USIZE + x;
+                grid.data.data[index / Self::WORD_BITS] |= 1 << (index % Self::WORD_BITS);
+                x += 1;
+            }
+            y += 1;
+        }
+        grid
+    };
+
+    fn bitwise_op_at<'a>(
+        &mut self,
+        other: impl Into<GridView<'a>>,
+        at: ArrayPoint<W, H>,
+        op: impl Fn(&mut BitSlice<BitSafeU64, Lsb0>, &BitSlice<u64, Lsb0>) + Copy,
+    ) -> Result<(), OutOfBounds> {
+        let other = other.into();
+        let mut view = ArrayRect::new(at, other.size()).map(|rect| self.get_mut(rect))?;
+
+        std::iter::zip(view.rows_mut(), other.rows()).for_each(|(dst_row, src_row)| op(dst_row, src_row));
+
+        Ok(())
+    }
+
+    /// Performs a logical AND operation with another grid `other` at `at`.
+    ///
+    /// Only points in the intersection of the two grids are affected.
+    ///
+    /// # Errors
+    ///
+    /// [`OutOfBounds`] if the `other` grid does not fit within `self` at `at`.
+    pub fn bitand_at<'a>(&mut self, other: impl Into<GridView<'a>>, at: ArrayPoint<W, H>) -> Result<(), OutOfBounds> {
+        self.bitwise_op_at(other, at, |dst, src| *dst &= src)
+    }
+
+    /// Performs a logical OR operation with another grid at the specified point.
+    ///
+    /// Only points in the intersection of the two grids are affected.
+    ///
+    /// # Errors
+    ///
+    /// [`OutOfBounds`] if the `other` grid does not fit within `self` at `at`.
+    pub fn bitor_at<'a>(&mut self, other: impl Into<GridView<'a>>, at: ArrayPoint<W, H>) -> Result<(), OutOfBounds> {
+        self.bitwise_op_at(other, at, |dst, src| *dst |= src)
+    }
+
+    /// Performs a logical XOR operation with another grid at the specified point.
     ///
     /// Only points in the intersection of the two grids are affected.
     ///
@@ -407,7 +1120,69 @@ impl<const W: u16, const H: u16, const WORDS: usize> ArrayGrid<W, H, WORDS> {
         self.bitwise_op_at(other, at, |dst, src| *dst ^= src)
     }
 
+    /// Replaces the region at `at` with the contents of `source`.
+    ///
+    /// Unlike [`bitor_at`](Self::bitor_at), unset cells in `source` clear the
+    /// corresponding cells in `self`.
+    ///
+    /// # Errors
+    ///
+    /// [`OutOfBounds`] if `source` does not fit within `self` at `at`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::str::FromStr;
+    /// # use grid_mask::{ArrayPoint, array_grid};
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut grid = <array_grid!(5, 3)>::FULL;
+    /// let source = <array_grid!(2, 2)>::from_str("# . . #")?;
+    ///
+    /// grid.blit(&source, ArrayPoint::new(3, 1)?)?;
+    /// assert_eq!(grid, <array_grid!(5, 3)>::from_str("##### ####. ###.#")?);
+    ///
+    /// assert!(grid.blit(&source, ArrayPoint::new(4, 1)?).is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn blit<'a>(&mut self, source: impl Into<GridView<'a>>, at: ArrayPoint<W, H>) -> Result<(), OutOfBounds> {
+        self.bitwise_op_at(source, at, BitSlice::clone_from_bitslice)
+    }
+
+    /// Copies the cells in `src` to the region of the same size at `dst`.
+    ///
+    /// The source and destination regions may overlap.
+    ///
+    /// # Arguments
+    ///
+    /// * `src` - The region to copy from.
+    /// * `dst` - The top-left point of the region to copy to.
+    ///
+    /// # Errors
+    ///
+    /// [`OutOfBounds`] if the destination region does not fit within the grid.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use grid_mask::{ArrayPoint, ArrayRect, array_grid};
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut grid = <array_grid!(10, 10)>::from_fn(|x, y| x < 2 && y < 2);
+    ///
+    /// grid.copy_region(ArrayRect::const_new::<0, 0, 2, 2>(), ArrayPoint::new(1, 1)?)?;
+    /// assert_eq!(grid, <array_grid!(10, 10)>::from_fn(|x, y| x < 3 && y < 3 && (x, y) != (2, 0) && (x, y) != (0, 2)));
+    ///
+    /// assert!(grid.copy_region(ArrayRect::const_new::<0, 0, 2, 2>(), ArrayPoint::new(9, 0)?).is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn copy_region(&mut self, src: ArrayRect<W, H>, dst: ArrayPoint<W, H>) -> Result<(), OutOfBounds> {
+        let source = self.clone();
+        self.blit(source.view_at(src), dst)
+    }
+
     const W_USIZE: usize = W as usize;
+    const H_USIZE: usize = H as usize;
 
     /// Clears the columns that incorrectly wrapped across row boundaries after
     /// the flat bit shift.
@@ -435,6 +1210,143 @@ impl<const W: u16, const H: u16, const WORDS: usize> ArrayGrid<W, H, WORDS> {
         self.clear_trailing_bits();
     }
 
+    /// Mirrors the grid left-to-right.
+    ///
+    /// The cell at `(x, y)` is moved to `(W - 1 - x, y)`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use grid_mask::{ArrayPoint, array_grid};
+    /// let mut grid = <array_grid!(10, 3)>::from_iter([ArrayPoint::ORIGIN]);
+    /// grid.flip_horizontal();
+    ///
+    /// assert!(grid.get(ArrayPoint::<10, 3>::const_new::<9, 0>()));
+    /// ```
+    pub fn flip_horizontal(&mut self) {
+        self.bits_mut().chunks_exact_mut(Self::W_USIZE).for_each(BitSlice::reverse);
+    }
+
+    /// Mirrors the grid top-to-bottom.
+    ///
+    /// The cell at `(x, y)` is moved to `(x, H - 1 - y)`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use grid_mask::{ArrayPoint, array_grid};
+    /// let mut grid = <array_grid!(10, 3)>::from_iter([ArrayPoint::ORIGIN]);
+    /// grid.flip_vertical();
+    ///
+    /// assert!(grid.get(ArrayPoint::<10, 3>::const_new::<0, 2>()));
+    /// ```
+    pub fn flip_vertical(&mut self) {
+        // reversing all cells mirrors both axes, reversing each row restores the column order
+        self.bits_mut().reverse();
+        self.flip_horizontal();
+    }
+
+    /// Swaps the contents of rows `r1` and `r2`.
+    ///
+    /// Does nothing if either row is out of bounds.
+    ///
+    /// # Arguments
+    ///
+    /// * `r1` - The first row to swap.
+    /// * `r2` - The second row to swap.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use grid_mask::{ArrayPoint, array_grid};
+    /// let mut grid = <array_grid!(10, 3)>::from_iter([ArrayPoint::ORIGIN]);
+    /// grid.swap_rows(0, 2);
+    ///
+    /// assert!(grid.get(ArrayPoint::<10, 3>::const_new::<0, 2>()));
+    /// assert_eq!(grid.count(), 1);
+    /// ```
+    pub fn swap_rows(&mut self, r1: u16, r2: u16) {
+        let (lo, hi) = (usize::from(r1.min(r2)), usize::from(r1.max(r2)));
+        if lo == hi || hi >= usize::from(H) {
+            return;
+        }
+
+        let (upper, lower) = self.bits_mut().split_at_mut(hi * Self::W_USIZE);
+        upper[lo * Self::W_USIZE..][..Self::W_USIZE].swap_with_bitslice(&mut lower[..Self::W_USIZE]);
+    }
+
+    /// Returns a copy of the grid mirrored left-to-right. See [`ArrayGrid::flip_horizontal`].
+    #[must_use]
+    pub fn flipped_horizontal(&self) -> Self {
+        let mut grid = self.clone();
+        grid.flip_horizontal();
+        grid
+    }
+
+    /// Returns a copy of the grid mirrored top-to-bottom. See [`ArrayGrid::flip_vertical`].
+    #[must_use]
+    pub fn flipped_vertical(&self) -> Self {
+        let mut grid = self.clone();
+        grid.flip_vertical();
+        grid
+    }
+
+    /// Returns a copy of the grid rotated 90 degrees clockwise.
+    ///
+    /// The cell at `(x, y)` is moved to `(H - 1 - y, x)`, so the width and height of the
+    /// result are swapped.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `WORDS_OUT` - The number of words of the rotated grid. This must equal `WORDS`, as
+    ///   the number of cells is unchanged; it is checked at compile time.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use grid_mask::{ArrayGrid, ArrayPoint};
+    /// let grid = ArrayGrid::<4, 8, 1>::from_iter([ArrayPoint::ORIGIN]);
+    /// let rotated: ArrayGrid<8, 4, 1> = grid.rotate_90();
+    ///
+    /// assert!(rotated.get(ArrayPoint::<8, 4>::const_new::<7, 0>()));
+    /// ```
+    ///
+    /// An incorrect `WORDS_OUT` fails at compile time:
+    ///
+    /// ```rust,compile_fail
+    /// # use grid_mask::{ArrayGrid, array_grid};
+    /// let rotated: ArrayGrid<10, 10, 1> = <array_grid!(10, 10)>::FULL.rotate_90();
+    /// ```
+    #[must_use]
+    pub fn rotate_90<const WORDS_OUT: usize>(&self) -> ArrayGrid<H, W, WORDS_OUT> {
+        // referencing the word count forces its compile time check for the output grid
+        let _: usize = ArrayGrid::<H, W, WORDS_OUT>::WORD_COUNT;
+
+        self.points().fold_mut(ArrayGrid::EMPTY, |rotated, point| {
+            let (x, y) = (H - 1 - point.y(), point.x());
+            rotated.set_at(usize::from(y) * usize::from(H) + usize::from(x), true);
+        })
+    }
+
+    /// Returns a copy of the grid rotated 180 degrees.
+    ///
+    /// The cell at `(x, y)` is moved to `(W - 1 - x, H - 1 - y)`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use grid_mask::{ArrayPoint, array_grid};
+    /// let grid = <array_grid!(10, 3)>::from_iter([ArrayPoint::ORIGIN]);
+    ///
+    /// assert_eq!(grid.rotate_180(), <array_grid!(10, 3)>::from_iter([ArrayPoint::MAX]));
+    /// ```
+    #[must_use]
+    pub fn rotate_180(&self) -> Self {
+        let mut grid = self.clone();
+        grid.bits_mut().reverse();
+        grid
+    }
+
     /// Provides the closure `f` with safe `mut` access to the underlying data.
     ///
     /// Note: This method provides the closure with the full `[u64]` slice. For grids
@@ -478,6 +1390,83 @@ impl<const W: u16, const H: u16, const WORDS: usize> From<[u64; WORDS]> for Arra
     }
 }
 
+/// Conversion from a [`GridMask`], which shares the same 8x8 layout.
+impl From<GridMask> for ArrayGrid<8, 8, 1> {
+    fn from(mask: GridMask) -> Self {
+        Self::from([mask.0])
+    }
+}
+
+/// Conversion into a [`GridMask`], which shares the same 8x8 layout.
+impl From<ArrayGrid<8, 8, 1>> for GridMask {
+    fn from(grid: ArrayGrid<8, 8, 1>) -> Self {
+        Self(grid.data.data[0])
+    }
+}
+
+/// Hashes the raw `[u64; WORDS]` data.
+///
+/// The unused trailing bits are always clear, so equal grids have equal data.
+impl<const W: u16, const H: u16, const WORDS: usize> Hash for ArrayGrid<W, H, WORDS> {
+    fn hash<S: Hasher>(&self, state: &mut S) {
+        self.data.data.hash(state);
+    }
+}
+
+/// Serializes the grid as its raw `[u64; WORDS]` data.
+#[cfg(feature = "serde")]
+impl<const W: u16, const H: u16, const WORDS: usize> serde::Serialize for ArrayGrid<W, H, WORDS> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeTuple;
+
+        let mut words = serializer.serialize_tuple(WORDS)?;
+        self.data().iter().try_for_each(|word| words.serialize_element(word))?;
+        words.end()
+    }
+}
+
+/// Deserializes the grid from its raw `[u64; WORDS]` data.
+///
+/// Note: if `W * H` is not a multiple of 64, the trailing bits of the last word will be cleared.
+#[cfg(feature = "serde")]
+impl<'de, const W: u16, const H: u16, const WORDS: usize> serde::Deserialize<'de> for ArrayGrid<W, H, WORDS> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct WordsVisitor<const WORDS: usize>;
+
+        impl<'de, const WORDS: usize> serde::de::Visitor<'de> for WordsVisitor<WORDS> {
+            type Value = [u64; WORDS];
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                write!(formatter, "an array of {WORDS} u64 words")
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: serde::de::SeqAccess<'de>,
+            {
+                let mut words = [0; WORDS];
+                words.iter_mut().enumerate().try_for_each(|(i, word)| {
+                    *word = seq.next_element()?.ok_or_else(|| serde::de::Error::invalid_length(i, &self))?;
+                    Ok(())
+                })?;
+
+                match seq.next_element::<serde::de::IgnoredAny>()? {
+                    Some(_) => Err(serde::de::Error::invalid_length(WORDS + 1, &self)),
+                    None => Ok(words),
+                }
+            }
+        }
+
+        deserializer.deserialize_tuple(WORDS, WordsVisitor::<WORDS>).map(Self::from)
+    }
+}
+
 impl<IDX, const W: u16, const H: u16, const WORDS: usize> FromIterator<IDX> for ArrayGrid<W, H, WORDS>
 where
     IDX: GridSetIndex<Self, SetOutput = ()>,
@@ -505,6 +1494,56 @@ where
     }
 }
 
+macro_rules! impl_bitwise_op {
+    ($($op:ident::$method:ident, $op_assign:ident::$method_assign:ident);* $(;)?) => {
+        $(
+            impl<const W: u16, const H: u16, const WORDS: usize> $op_assign<&Self> for ArrayGrid<W, H, WORDS> {
+                fn $method_assign(&mut self, rhs: &Self) {
+                    std::iter::zip(&mut self.data.data, &rhs.data.data).for_each(|(word, rhs)| word.$method_assign(rhs));
+                }
+            }
+
+            impl<const W: u16, const H: u16, const WORDS: usize> $op_assign for ArrayGrid<W, H, WORDS> {
+                fn $method_assign(&mut self, rhs: Self) {
+                    self.$method_assign(&rhs);
+                }
+            }
+
+            impl<const W: u16, const H: u16, const WORDS: usize> $op<&Self> for ArrayGrid<W, H, WORDS> {
+                type Output = Self;
+
+                fn $method(mut self, rhs: &Self) -> Self::Output {
+                    self.$method_assign(rhs);
+                    self
+                }
+            }
+
+            impl<const W: u16, const H: u16, const WORDS: usize> $op for ArrayGrid<W, H, WORDS> {
+                type Output = Self;
+
+                fn $method(self, rhs: Self) -> Self::Output {
+                    self.$method(&rhs)
+                }
+            }
+        )*
+    };
+}
+
+impl_bitwise_op! {
+    BitAnd::bitand, BitAndAssign::bitand_assign;
+    BitOr::bitor, BitOrAssign::bitor_assign;
+    BitXor::bitxor, BitXorAssign::bitxor_assign;
+}
+
+impl<const W: u16, const H: u16, const WORDS: usize> Not for ArrayGrid<W, H, WORDS> {
+    type Output = Self;
+
+    fn not(mut self) -> Self::Output {
+        self.negate();
+        self
+    }
+}
+
 impl<const W: u16, const H: u16, const WORDS: usize> FromStr for ArrayGrid<W, H, WORDS> {
     type Err = PatternError;
 
@@ -513,24 +1552,7 @@ impl<const W: u16, const H: u16, const WORDS: usize> FromStr for ArrayGrid<W, H,
     /// Uses `#` for set cells and `.` for unset cells.
     /// Whitespace is ignored.
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        s.chars()
-            .filter(NotWhitespace::is_not_whitespace)
-            .take(Self::CELLS_USZ + 1)
-            .enumerate()
-            .map(|(i, c)| (ArrayIndex::try_new(i), c))
-            .try_fold((Self::EMPTY, None), |(mut grid, _), (i, c)| match (i, c) {
-                (Err(_), _) => Err(PatternError::TooLong),
-                (Ok(i), '#') => {
-                    grid.set(i, true);
-                    (grid, Some(i)).into_ok()
-                }
-                (Ok(i), '.') => (grid, Some(i)).into_ok(),
-                (_, c) => PatternError::InvalidChar(c).into_err(),
-            })
-            .and_then(|(grid, index)| match index.map_or(0, |i| i.get() + 1) {
-                i if i == Self::CELLS => Ok(grid),
-                i => PatternError::TooShort(i).into_err(),
-            })
+        Self::from_pattern(s, '#', '.')
     }
 }
 
@@ -0,0 +1,42 @@
+use std::marker::PhantomData;
+
+use crate::Adjacency;
+use crate::array::ArrayGrid;
+
+/// An iterator over the connected components of an [`ArrayGrid`].
+///
+/// # Type Parameters
+///
+/// * `A` - The [`Adjacency`] strategy used to connect cells.
+#[derive(Debug, Clone)]
+pub struct ComponentIter<A, const W: u16, const H: u16, const WORDS: usize> {
+    remaining: ArrayGrid<W, H, WORDS>,
+    _adj: PhantomData<A>,
+}
+
+impl<A: Adjacency, const W: u16, const H: u16, const WORDS: usize> ComponentIter<A, W, H, WORDS> {
+    pub(crate) const fn new(grid: ArrayGrid<W, H, WORDS>) -> Self {
+        Self { remaining: grid, _adj: PhantomData }
+    }
+}
+
+impl<A: Adjacency, const W: u16, const H: u16, const WORDS: usize> Iterator for ComponentIter<A, W, H, WORDS> {
+    type Item = ArrayGrid<W, H, WORDS>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let seed = self.remaining.points().next()?;
+        let component = self.remaining.connected::<A>(seed);
+        self.remaining ^= &component;
+        Some(component)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let count = self.remaining.count() as usize;
+        (count.min(1), Some(count))
+    }
+}
+
+impl<A: Adjacency, const W: u16, const H: u16, const WORDS: usize> std::iter::FusedIterator
+    for ComponentIter<A, W, H, WORDS>
+{
+}
@@ -1,7 +1,9 @@
 mod cells;
+mod components;
 mod points;
 mod spaces;
 
 pub use cells::Cells;
+pub use components::ComponentIter;
 pub use points::Points;
 pub use spaces::Spaces;
@@ -1,3 +1,5 @@
+use crate::GridVector;
+
 /// A 2D displacement vector for translating an [`ArrayGrid`](super::ArrayGrid).
 ///
 /// Components are `i32` to cover the full range of `u16` grid dimensions.
@@ -43,6 +45,12 @@ impl ArrayVector {
     pub const WEST: Self = Self::new(-1, 0);
 }
 
+impl From<GridVector> for ArrayVector {
+    fn from(vec: GridVector) -> Self {
+        Self::new(vec.x.into(), vec.y.into())
+    }
+}
+
 // impl From<(i32, i32)> for ArrayVector {
 //     fn from((dx, dy): (i32, i32)) -> Self {
 //         Self::new(dx, dy)
@@ -77,6 +77,57 @@ impl GridView<'_> {
         self.rows().map(BitSlice::count_ones).sum()
     }
 
+    /// Returns `true` if every cell in the view is set.
+    #[must_use]
+    pub fn all(&self) -> bool {
+        self.rows().all(BitSlice::all)
+    }
+
+    /// Returns `true` if at least one cell in the view is set.
+    #[must_use]
+    pub fn any(&self) -> bool {
+        self.rows().any(BitSlice::any)
+    }
+
+    /// Returns `true` if no cells in the view are set.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        !self.any()
+    }
+
+    /// Returns `true` if every cell in the view is set. Equivalent to [`all`](Self::all).
+    #[must_use]
+    pub fn is_full(&self) -> bool {
+        self.all()
+    }
+
+    /// Returns `true` if every set cell in this view is also set in `other`.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The view to compare against.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the views have different sizes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use grid_mask::{ArrayRect, array_grid};
+    /// let grid = <array_grid!(8, 8)>::from_fn(|x, _| x < 4);
+    /// let piece = <array_grid!(2, 2)>::from_fn(|x, y| x == y);
+    /// let piece = piece.get(ArrayRect::const_new::<0, 0, 2, 2>());
+    ///
+    /// assert!(piece.is_subset_of(grid.get(ArrayRect::const_new::<2, 0, 2, 2>())));
+    /// assert!(!piece.is_subset_of(grid.get(ArrayRect::const_new::<3, 0, 2, 2>())));
+    /// ```
+    #[must_use]
+    pub fn is_subset_of(&self, other: GridView<'_>) -> bool {
+        assert_eq!(self.size(), other.size(), "views must have the same size");
+        std::iter::zip(self.rows(), other.rows()).all(|(row, other)| row.iter_ones().all(|x| other[x]))
+    }
+
     /// Returns the value of the cell at `point` using coordinates local to this view.
     ///
     /// # Arguments
@@ -107,6 +158,14 @@ impl GridView<'_> {
         self.rows().enumerate().flat_map(|(y, row)| row.iter_ones().map(move |x| Point::new(x as u16, y as u16)))
     }
 
+    /// Returns an iterator over the positions of all set cells in the view.
+    ///
+    /// Unlike [`points`](Self::points), the coordinates are relative to the underlying grid.
+    pub fn global_points(&self) -> impl Iterator<Item = Point<u16>> + '_ {
+        let origin = self.rect.point;
+        self.points().map(move |point| Point::new(origin.x + point.x, origin.y + point.y))
+    }
+
     /// Returns an iterator over the positions of all unset cells in the view.
     ///
     /// The coordinates are local to the view.
@@ -176,6 +235,31 @@ impl GridViewMut<'_> {
         self.rows_mut().for_each(|row| row.fill(value));
     }
 
+    /// Replaces the contents of this view with the contents of `source`.
+    ///
+    /// # Arguments
+    ///
+    /// * `source` - The view to copy from.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the views have different sizes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use grid_mask::{ArrayRect, array_grid};
+    /// let saved = <array_grid!(2, 2)>::from_fn(|x, y| x == y);
+    /// let mut grid = <array_grid!(8, 8)>::FULL;
+    ///
+    /// grid.get_mut(ArrayRect::const_new::<3, 3, 2, 2>()).fill_from(saved.get(ArrayRect::const_new::<0, 0, 2, 2>()));
+    /// assert_eq!(grid.count(), 62);
+    /// ```
+    pub fn fill_from(&mut self, source: GridView<'_>) {
+        assert_eq!(self.size(), source.size(), "views must have the same size");
+        std::iter::zip(self.rows_mut(), source.rows()).for_each(|(dst, src)| dst.clone_from_bitslice(src));
+    }
+
     /// Clears the view.
     pub fn clear(&mut self) {
         self.fill(false);
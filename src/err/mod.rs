@@ -1,7 +1,9 @@
 mod discontiguous;
 mod out_of_bounds;
+mod parse_error;
 mod pattern_error;
 
 pub use discontiguous::Discontiguous;
 pub use out_of_bounds::OutOfBounds;
+pub use parse_error::ParseError;
 pub use pattern_error::{PatternError, ShapePatternError};
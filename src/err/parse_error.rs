@@ -0,0 +1,10 @@
+/// Errors parsing a hexadecimal [`str`] into a [`GridMask`](crate::GridMask).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+pub enum ParseError {
+    /// The string does not contain exactly 16 characters.
+    #[error("Hex string must be 16 characters, found {0}")]
+    WrongLength(usize),
+    /// The string contains a character that is not a hexadecimal digit.
+    #[error("Invalid character '{0}' in hex string")]
+    InvalidChar(char),
+}
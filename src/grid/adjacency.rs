@@ -3,6 +3,9 @@ use super::{GridMask, GridVector};
 /// Defines how a mask grows to include adjacent cells.
 #[sealed::sealed]
 pub trait Adjacency: Sized {
+    /// The offsets from a cell to each of its neighbors.
+    const NEIGHBORS: &'static [GridVector];
+
     /// Returns a mask of all cells adjacent to `data` (including `data` itself).
     ///
     /// # Arguments
@@ -28,6 +31,8 @@ pub struct Cardinal;
 
 #[sealed::sealed]
 impl Adjacency for Cardinal {
+    const NEIGHBORS: &'static [GridVector] = &GridVector::ALL_CARDINAL;
+
     fn connected(mask: GridMask) -> GridMask {
         let north = mask.translate(GridVector::NORTH);
         let south = mask.translate(GridVector::SOUTH);
@@ -44,6 +49,8 @@ pub struct Octile;
 
 #[sealed::sealed]
 impl Adjacency for Octile {
+    const NEIGHBORS: &'static [GridVector] = &GridVector::ALL_OCTILE;
+
     fn connected(mask: GridMask) -> GridMask {
         let n = mask.translate(GridVector::NORTH);
         let s = mask.translate(GridVector::SOUTH);
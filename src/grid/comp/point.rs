@@ -1,10 +1,13 @@
+use std::ops::{Add, Sub};
+
 use fluent_result::into::IntoResult;
 use tap::Pipe;
 
-use crate::GridVector;
 use crate::err::OutOfBounds;
 use crate::ext::{Bound, BoundedIter};
+use crate::grid::LinePoints;
 use crate::num::{BitIndexU64, GridPos};
+use crate::{Adjacency, GridMask, GridVector};
 
 /// A point in a 8x8 grid.
 #[derive(
@@ -166,6 +169,52 @@ impl GridPoint {
         Self(index)
     }
 
+    /// Tries to create a new [`GridPoint`] from a row-major index.
+    ///
+    /// Index `i` maps to the point `(i % 8, i / 8)`. This is the inverse of
+    /// [`to_linear_index`](Self::to_linear_index).
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The row-major index of the point.
+    ///
+    /// # Errors
+    ///
+    /// [`OutOfBounds`] if `index` is greater than `63`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use grid_mask::GridPoint;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// assert_eq!(GridPoint::from_linear_index(0)?, GridPoint::ORIGIN);
+    /// assert_eq!(GridPoint::from_linear_index(11)?, (3, 1));
+    ///
+    /// GridPoint::from_linear_index(64).expect_err("Should be invalid");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_linear_index(index: u8) -> Result<Self, OutOfBounds> {
+        BitIndexU64::try_from(index).map(Self).map_err(OutOfBounds::from)
+    }
+
+    /// Returns the row-major index of the point, `y * 8 + x`.
+    ///
+    /// This is the inverse of [`from_linear_index`](Self::from_linear_index).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use grid_mask::GridPoint;
+    /// assert_eq!(GridPoint::ORIGIN.to_linear_index(), 0);
+    /// assert_eq!(GridPoint::const_new::<3, 1>().to_linear_index(), 11);
+    /// assert_eq!(GridPoint::MAX.to_linear_index(), 63);
+    /// ```
+    #[must_use]
+    pub const fn to_linear_index(&self) -> u8 {
+        self.0.get()
+    }
+
     /// Returns the x coordinate of the point.
     ///
     /// # Examples
@@ -242,6 +291,98 @@ impl GridPoint {
         Self::try_new(x, y)
     }
 
+    /// Returns a [`GridMask`] of the cells adjacent to the point under the provided
+    /// [`Adjacency`], not including the point itself.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `A` - The [`Adjacency`] strategy to use.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use grid_mask::{GridPoint, Cardinal, Octile};
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let neighbors = GridPoint::ORIGIN.neighbors::<Cardinal>();
+    ///
+    /// assert_eq!(neighbors.count(), 2);
+    /// assert!(neighbors.get(GridPoint::try_new(1, 0)?));
+    /// assert!(neighbors.get(GridPoint::try_new(0, 1)?));
+    ///
+    /// assert_eq!(GridPoint::try_new(4, 4)?.neighbors::<Octile>().count(), 8);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn neighbors<A: Adjacency>(&self) -> GridMask {
+        GridMask::from(*self).frontier::<A>()
+    }
+
+    /// Returns an iterator over the points on the line from the point to `other`,
+    /// including both endpoints.
+    ///
+    /// The line is rasterized using Bresenham's algorithm, matching [`GridMask::line`].
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The last point of the line.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use grid_mask::GridPoint;
+    /// let points: Vec<_> = GridPoint::ORIGIN.all_between(GridPoint::const_new::<3, 1>()).collect();
+    ///
+    /// assert_eq!(points, [(0, 0), (1, 0), (2, 1), (3, 1)]);
+    /// assert_eq!(GridPoint::ORIGIN.all_between(GridPoint::MAX).count(), 8);
+    /// ```
+    #[must_use]
+    pub const fn all_between(&self, other: Self) -> LinePoints {
+        LinePoints::new(*self, other)
+    }
+
+    /// Returns the Manhattan distance between the point and `other`.
+    ///
+    /// This is the sum of the absolute differences of the coordinates, at most `14`.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The point to measure the distance to.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use grid_mask::GridPoint;
+    /// assert_eq!(GridPoint::ORIGIN.manhattan_distance(GridPoint::MAX), 14);
+    /// assert_eq!(GridPoint::const_new::<2, 5>().manhattan_distance(GridPoint::const_new::<4, 1>()), 6);
+    /// ```
+    #[must_use]
+    pub const fn manhattan_distance(&self, other: Self) -> u8 {
+        self.x().get().abs_diff(other.x().get()) + self.y().get().abs_diff(other.y().get())
+    }
+
+    /// Returns the Chebyshev distance between the point and `other`.
+    ///
+    /// This is the largest absolute difference of the coordinates, at most `7`.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The point to measure the distance to.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use grid_mask::GridPoint;
+    /// assert_eq!(GridPoint::ORIGIN.chebyshev_distance(GridPoint::MAX), 7);
+    /// assert_eq!(GridPoint::const_new::<2, 5>().chebyshev_distance(GridPoint::const_new::<4, 1>()), 4);
+    /// ```
+    #[must_use]
+    pub const fn chebyshev_distance(&self, other: Self) -> u8 {
+        let dx = self.x().get().abs_diff(other.x().get());
+        let dy = self.y().get().abs_diff(other.y().get());
+        if dx > dy { dx } else { dy }
+    }
+
     /// Returns an iterator over all possible [`GridPoint`] values.
     #[must_use]
     pub const fn all_values() -> BoundedIter<Self> {
@@ -274,6 +415,24 @@ where
     }
 }
 
+impl Add<GridVector> for GridPoint {
+    type Output = Result<Self, OutOfBounds>;
+
+    /// Translates the point by `rhs`. See [`GridPoint::translate`].
+    fn add(self, rhs: GridVector) -> Self::Output {
+        self.translate(rhs)
+    }
+}
+
+impl Sub for GridPoint {
+    type Output = GridVector;
+
+    /// Returns the displacement from `rhs` to `self`.
+    fn sub(self, rhs: Self) -> Self::Output {
+        GridVector::from_points(rhs, self)
+    }
+}
+
 impl Bound for GridPoint {
     const MIN: Self = Self::ORIGIN;
     const MAX: Self = Self::MAX;
@@ -1,12 +1,15 @@
 use fluent_result::bool::Then;
-use fluent_result::into::IntoResult;
+use fluent_result::into::{IntoOption, IntoResult};
 
 use crate::err::OutOfBounds;
+use crate::grid::RectPoints;
 use crate::num::{GridLen, GridPos};
-use crate::{GridPoint, GridSize, GridVector};
+use crate::{GridMask, GridPoint, GridSize, GridVector};
 
 /// A rectangle on an 8x8 grid.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, derive_more::Display)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(into = "GridRectSerde", try_from = "GridRectSerde"))]
 #[display("{point} {size}")] // GridPoint is (x, y), GridSize is (WxH)
 pub struct GridRect {
     /// The top-left corner of the rectangle.
@@ -15,6 +18,31 @@ pub struct GridRect {
     size: GridSize,
 }
 
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct GridRectSerde {
+    x: GridPos,
+    y: GridPos,
+    w: GridLen,
+    h: GridLen,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<GridRectSerde> for GridRect {
+    type Error = OutOfBounds;
+
+    fn try_from(value: GridRectSerde) -> Result<Self, Self::Error> {
+        Self::new(GridPoint::new(value.x, value.y), GridSize { width: value.w, height: value.h })
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<GridRect> for GridRectSerde {
+    fn from(value: GridRect) -> Self {
+        Self { x: value.x(), y: value.y(), w: value.w(), h: value.h() }
+    }
+}
+
 impl GridRect {
     /// A maximum size [`GridRect`].
     pub const MAX: Self = Self { point: GridPoint::ORIGIN, size: GridSize::MAX };
@@ -79,6 +107,33 @@ impl GridRect {
         Self { point: GridPoint::const_new::<X, Y>(), size: GridSize::const_new::<W, H>() }
     }
 
+    /// Creates the smallest [`GridRect`] containing both `p1` and `p2`.
+    ///
+    /// The points may be any two opposite corners of the rectangle, in either order.
+    ///
+    /// # Arguments
+    ///
+    /// * `p1` - A corner of the rectangle.
+    /// * `p2` - The opposite corner of the rectangle.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use grid_mask::{GridPoint, GridRect};
+    /// assert_eq!(GridRect::from_corners(GridPoint::ORIGIN, GridPoint::MAX), GridRect::MAX);
+    ///
+    /// let rect = GridRect::from_corners(GridPoint::const_new::<5, 1>(), GridPoint::const_new::<2, 3>());
+    /// assert_eq!(rect, GridRect::const_new::<2, 1, 4, 3>());
+    /// ```
+    #[must_use]
+    pub fn from_corners(p1: GridPoint, p2: GridPoint) -> Self {
+        let (left, right) = (p1.x().min(p2.x()).get(), p1.x().max(p2.x()).get());
+        let (top, bottom) = (p1.y().min(p2.y()).get(), p1.y().max(p2.y()).get());
+
+        let size = GridSize::new_unchecked(right - left + 1, bottom - top + 1);
+        Self::new_unchecked(GridPoint::new_unchecked(left, top), size)
+    }
+
     /// Returns the position of the bottom-right cell occupied by the rectangle.
     ///
     /// Since [`GridRect`] is guaranteed to be within the grid, this method is infallible.
@@ -126,6 +181,20 @@ impl GridRect {
         self.size
     }
 
+    /// Returns the number of cells in the rectangle.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use grid_mask::GridRect;
+    /// assert_eq!(GridRect::MAX.area(), 64);
+    /// assert_eq!(GridRect::const_new::<2, 2, 3, 4>().area(), 12);
+    /// ```
+    #[must_use]
+    pub const fn area(&self) -> u8 {
+        self.size.area()
+    }
+
     /// Translates the rectangle by the given vector.
     ///
     /// The rectangle can not be "clipped" by the grid boundaries.
@@ -159,4 +228,310 @@ impl GridRect {
         let point = self.point.translate(vec)?;
         Self::new(point, self.size)
     }
+
+    /// Translates the rectangle by the given vector, clamping it so that it stays fully
+    /// within the grid.
+    ///
+    /// Unlike [`GridRect::translate`], this never fails. Motion along an axis stops at the
+    /// grid boundary, while the size of the rectangle is preserved.
+    ///
+    /// # Arguments
+    ///
+    /// * `vec` - The vector to translate the rectangle by.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use grid_mask::{GridRect, GridVector};
+    /// let rect = GridRect::const_new::<6, 0, 2, 8>();
+    /// assert_eq!(rect.translate_clamped(GridVector::EAST), rect);
+    ///
+    /// let rect = GridRect::const_new::<3, 4, 2, 2>();
+    /// let translated = rect.translate_clamped(GridVector::new(-5, 1));
+    /// assert_eq!(translated, GridRect::const_new::<0, 5, 2, 2>());
+    /// ```
+    #[must_use]
+    pub fn translate_clamped(&self, vec: GridVector) -> Self {
+        const fn clamp_axis(pos: u8, len: u8, delta: i8) -> u8 {
+            let max = GridLen::MAX.get() - len;
+            match pos.saturating_add_signed(delta) {
+                pos if pos > max => max,
+                pos => pos,
+            }
+        }
+
+        let x = clamp_axis(self.x().get(), self.w().get(), vec.x);
+        let y = clamp_axis(self.y().get(), self.h().get(), vec.y);
+        Self::new_unchecked(GridPoint::new_unchecked(x, y), self.size)
+    }
+
+    /// Returns `true` if `point` lies within the rectangle.
+    ///
+    /// # Arguments
+    ///
+    /// * `point` - The point to check.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use grid_mask::{GridPoint, GridRect};
+    /// let rect = GridRect::const_new::<2, 2, 3, 3>();
+    ///
+    /// assert!(rect.contains_point(GridPoint::const_new::<2, 4>()));
+    /// assert!(!rect.contains_point(GridPoint::const_new::<5, 2>()));
+    /// assert!(GridRect::MAX.contains_point(GridPoint::MAX));
+    /// ```
+    #[must_use]
+    pub const fn contains_point(&self, point: GridPoint) -> bool {
+        let (x, y) = (point.x().get(), point.y().get());
+        let (left, top) = (self.x().get(), self.y().get());
+
+        x >= left && x < left + self.w().get() && y >= top && y < top + self.h().get()
+    }
+
+    /// Returns `true` if every cell of `other` lies within the rectangle.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The rectangle to check.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use grid_mask::GridRect;
+    /// let rect = GridRect::const_new::<2, 2, 3, 3>();
+    ///
+    /// assert!(GridRect::MAX.contains_rect(rect));
+    /// assert!(rect.contains_rect(rect));
+    /// assert!(!rect.contains_rect(GridRect::MAX));
+    /// ```
+    #[must_use]
+    pub const fn contains_rect(&self, other: Self) -> bool {
+        other.x().get() >= self.x().get()
+            && other.y().get() >= self.y().get()
+            && other.x().get() + other.w().get() <= self.x().get() + self.w().get()
+            && other.y().get() + other.h().get() <= self.y().get() + self.h().get()
+    }
+
+    /// Returns the rectangle of cells shared by the rectangle and `other`, or [`None`] if
+    /// they do not overlap.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The rectangle to intersect with.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use grid_mask::GridRect;
+    /// let rect = GridRect::const_new::<2, 2, 4, 4>();
+    ///
+    /// assert_eq!(GridRect::MAX.intersection(rect), Some(rect));
+    /// assert_eq!(rect.intersection(GridRect::const_new::<5, 0, 3, 3>()), Some(GridRect::const_new::<5, 2, 1, 1>()));
+    /// assert_eq!(rect.intersection(GridRect::const_new::<6, 0, 2, 2>()), None);
+    /// ```
+    #[must_use]
+    pub fn intersection(&self, other: Self) -> Option<Self> {
+        let left = self.x().get().max(other.x().get());
+        let top = self.y().get().max(other.y().get());
+        let right = (self.x().get() + self.w().get()).min(other.x().get() + other.w().get());
+        let bottom = (self.y().get() + self.h().get()).min(other.y().get() + other.h().get());
+
+        (right > left && bottom > top).then(|| {
+            let point = GridPoint::new_unchecked(left, top);
+            let size = GridSize::new_unchecked(right - left, bottom - top);
+            Self::new_unchecked(point, size)
+        })
+    }
+
+    /// Grows the rectangle outward by `margin` cells on every side.
+    ///
+    /// # Arguments
+    ///
+    /// * `margin` - The number of cells to add on each side.
+    ///
+    /// # Errors
+    ///
+    /// [`OutOfBounds`] if the resulting rectangle would extend beyond the grid.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use grid_mask::GridRect;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// assert_eq!(GridRect::const_new::<1, 1, 6, 6>().expand(1)?, GridRect::MAX);
+    ///
+    /// GridRect::MAX.expand(1).expect_err("Should be out of bounds");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn expand(&self, margin: u8) -> Result<Self, OutOfBounds> {
+        let x = self.x().get().checked_sub(margin).ok_or(OutOfBounds)?;
+        let y = self.y().get().checked_sub(margin).ok_or(OutOfBounds)?;
+
+        // margin is at most 7 here, so this can not overflow
+        Self::new((x, y), (self.w().get() + 2 * margin, self.h().get() + 2 * margin))
+    }
+
+    /// Shrinks the rectangle inward by `margin` cells on every side.
+    ///
+    /// # Arguments
+    ///
+    /// * `margin` - The number of cells to remove from each side.
+    ///
+    /// # Returns
+    ///
+    /// The shrunk rectangle, or [`None`] if it would have no width or height.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use grid_mask::GridRect;
+    /// assert_eq!(GridRect::MAX.shrink(1), Some(GridRect::const_new::<1, 1, 6, 6>()));
+    /// assert_eq!(GridRect::MAX.shrink(4), None);
+    /// ```
+    #[must_use]
+    pub fn shrink(&self, margin: u8) -> Option<Self> {
+        let inset = margin.checked_mul(2)?;
+        let w = self.w().get().checked_sub(inset).filter(|&w| w > 0)?;
+        let h = self.h().get().checked_sub(inset).filter(|&h| h > 0)?;
+
+        let point = GridPoint::new_unchecked(self.x().get() + margin, self.y().get() + margin);
+        Self::new_unchecked(point, GridSize::new_unchecked(w, h)).into_some()
+    }
+
+    /// Splits the rectangle into a top and a bottom part, with `rows_from_top` rows in the
+    /// top part.
+    ///
+    /// # Arguments
+    ///
+    /// * `rows_from_top` - The height of the top part.
+    ///
+    /// # Returns
+    ///
+    /// The top and bottom parts, or [`None`] if either part would be empty, i.e. if
+    /// `rows_from_top` is `0` or not less than the height.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use grid_mask::GridRect;
+    /// let (top, bottom) = GridRect::MAX.split_at_row(4).unwrap();
+    ///
+    /// assert_eq!(top, GridRect::const_new::<0, 0, 8, 4>());
+    /// assert_eq!(bottom, GridRect::const_new::<0, 4, 8, 4>());
+    /// assert_eq!(GridRect::MAX.split_at_row(8), None);
+    /// ```
+    #[must_use]
+    pub fn split_at_row(&self, rows_from_top: u8) -> Option<(Self, Self)> {
+        let (x, y, w, h) = (self.x().get(), self.y().get(), self.w().get(), self.h().get());
+        (rows_from_top > 0 && rows_from_top < h).then(|| {
+            let top = Self::new_unchecked(self.point, GridSize::new_unchecked(w, rows_from_top));
+            let bottom_point = GridPoint::new_unchecked(x, y + rows_from_top);
+            let bottom = Self::new_unchecked(bottom_point, GridSize::new_unchecked(w, h - rows_from_top));
+            (top, bottom)
+        })
+    }
+
+    /// Splits the rectangle into a left and a right part, with `cols_from_left` columns in
+    /// the left part.
+    ///
+    /// # Arguments
+    ///
+    /// * `cols_from_left` - The width of the left part.
+    ///
+    /// # Returns
+    ///
+    /// The left and right parts, or [`None`] if either part would be empty, i.e. if
+    /// `cols_from_left` is `0` or not less than the width.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use grid_mask::GridRect;
+    /// let (left, right) = GridRect::MAX.split_at_col(3).unwrap();
+    ///
+    /// assert_eq!(left, GridRect::const_new::<0, 0, 3, 8>());
+    /// assert_eq!(right, GridRect::const_new::<3, 0, 5, 8>());
+    /// assert_eq!(GridRect::MAX.split_at_col(0), None);
+    /// ```
+    #[must_use]
+    pub fn split_at_col(&self, cols_from_left: u8) -> Option<(Self, Self)> {
+        let (x, y, w, h) = (self.x().get(), self.y().get(), self.w().get(), self.h().get());
+        (cols_from_left > 0 && cols_from_left < w).then(|| {
+            let left = Self::new_unchecked(self.point, GridSize::new_unchecked(cols_from_left, h));
+            let right_point = GridPoint::new_unchecked(x + cols_from_left, y);
+            let right = Self::new_unchecked(right_point, GridSize::new_unchecked(w - cols_from_left, h));
+            (left, right)
+        })
+    }
+
+    /// Returns a [`GridMask`] of the cells on the outer ring of the rectangle.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use grid_mask::{GridMask, GridRect};
+    /// assert_eq!(GridRect::MAX.perimeter_mask(), GridMask::GRID_BOUNDARY);
+    /// assert_eq!(GridRect::const_new::<2, 2, 3, 3>().perimeter_mask().count(), 8);
+    /// ```
+    #[must_use]
+    pub fn perimeter_mask(&self) -> GridMask {
+        GridMask::from(*self) & !self.shrink(1).map_or(GridMask::EMPTY, GridMask::from)
+    }
+
+    /// Returns the cell nearest to the center of the rectangle.
+    ///
+    /// For an even width or height there are two central cells along that axis, and the
+    /// one further from the top-left corner is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use grid_mask::{GridPoint, GridRect};
+    /// assert_eq!(GridRect::MAX.center_point(), GridPoint::const_new::<4, 4>());
+    /// assert_eq!(GridRect::const_new::<2, 2, 3, 3>().center_point(), GridPoint::const_new::<3, 3>());
+    /// ```
+    #[must_use]
+    pub fn center_point(&self) -> GridPoint {
+        // x + w / 2 is at most x + w - 1, which is within the grid
+        GridPoint::new_unchecked(self.x().get() + self.w().get() / 2, self.y().get() + self.h().get() / 2)
+    }
+
+    /// Returns an iterator over the positions of all cells of the rectangle.
+    ///
+    /// Iterates in row-major order, from the top-left cell to the bottom-right cell.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use grid_mask::{GridPoint, GridRect};
+    /// let points: Vec<GridPoint> = GridRect::const_new::<0, 0, 2, 2>().points().collect();
+    ///
+    /// assert_eq!(points, [(0, 0), (1, 0), (0, 1), (1, 1)]);
+    /// assert_eq!(GridRect::MAX.points().len(), 64);
+    /// ```
+    #[must_use]
+    pub const fn points(&self) -> RectPoints {
+        RectPoints::new(*self)
+    }
+}
+
+impl IntoIterator for GridRect {
+    type Item = GridPoint;
+    type IntoIter = RectPoints;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.points()
+    }
+}
+
+#[expect(clippy::into_iter_without_iter, reason = "`GridRect::points` serves as the `iter` method")]
+impl IntoIterator for &GridRect {
+    type Item = GridPoint;
+    type IntoIter = RectPoints;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.points()
+    }
 }
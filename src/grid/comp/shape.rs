@@ -46,6 +46,28 @@ pub struct GridShape<A = Cardinal>(
     #[into(skip)] PhantomData<A>,
 );
 
+/// Serializes as the raw `u64` bitmask.
+#[cfg(feature = "serde")]
+impl<A> serde::Serialize for GridShape<A> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_u64(self.0.0)
+    }
+}
+
+/// Deserializes from any [`GridMask`] format, rejecting masks that are not contiguous.
+#[cfg(feature = "serde")]
+impl<'de, A: Adjacency> serde::Deserialize<'de> for GridShape<A> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        GridMask::deserialize(deserializer)?.try_into().map_err(serde::de::Error::custom)
+    }
+}
+
 impl<A: Adjacency> GridShape<A> {
     /// A shape that contains all cells.
     pub const FULL: Self = Self(GridMask::FULL, PhantomData);
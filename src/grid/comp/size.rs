@@ -135,6 +135,66 @@ impl GridSize {
         let height = height.try_into().map_err(OutOfBounds::from)?;
         Self { width, height }.into_ok()
     }
+
+    /// Returns the number of cells covered by the size.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use grid_mask::GridSize;
+    /// assert_eq!(GridSize::MAX.area(), 64);
+    /// assert_eq!(GridSize::const_new::<2, 3>().area(), 6);
+    /// ```
+    #[must_use]
+    pub const fn area(&self) -> u8 {
+        self.width.get() * self.height.get()
+    }
+
+    /// Returns `true` if the width and height are equal.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use grid_mask::GridSize;
+    /// assert!(GridSize::const_new::<4, 4>().is_square());
+    /// assert!(!GridSize::const_new::<4, 2>().is_square());
+    /// ```
+    #[must_use]
+    pub const fn is_square(&self) -> bool {
+        self.width.get() == self.height.get()
+    }
+
+    /// Returns the larger of the width and height.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use grid_mask::GridSize;
+    /// assert_eq!(GridSize::const_new::<2, 5>().max_dim().get(), 5);
+    /// ```
+    #[must_use]
+    pub const fn max_dim(&self) -> GridLen {
+        match self.width.get() >= self.height.get() {
+            true => self.width,
+            false => self.height,
+        }
+    }
+
+    /// Returns the smaller of the width and height.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use grid_mask::GridSize;
+    /// assert_eq!(GridSize::const_new::<2, 5>().min_dim().get(), 2);
+    /// ```
+    #[must_use]
+    pub const fn min_dim(&self) -> GridLen {
+        match self.width.get() <= self.height.get() {
+            true => self.width,
+            false => self.height,
+        }
+    }
 }
 
 impl<W: From<GridLen>, H: From<GridLen>> From<GridSize> for (W, H) {
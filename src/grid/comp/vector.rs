@@ -1,4 +1,26 @@
+use crate::GridPoint;
+
 /// An unbounded 2D vector with unsigned components, representing a shift or displacement.
+///
+/// Supports componentwise [`Add`](core::ops::Add) and [`Sub`](core::ops::Sub), negation
+/// via [`Neg`](core::ops::Neg), and scalar multiplication via [`Mul<i8>`](core::ops::Mul).
+/// These follow the overflow behavior of [`i8`] arithmetic: they panic in debug builds
+/// and wrap in release builds.
+///
+/// # Panics
+///
+/// In debug builds, negating a vector with an [`i8::MIN`] component panics, as do sums,
+/// differences, and products that overflow an [`i8`] component.
+///
+/// # Examples
+///
+/// ```rust
+/// # use grid_mask::GridVector;
+/// assert_eq!(GridVector::NORTH + GridVector::EAST, GridVector::NORTH_EAST);
+/// assert_eq!(GridVector::NORTH_EAST - GridVector::EAST, GridVector::NORTH);
+/// assert_eq!(-GridVector::SOUTH, GridVector::NORTH);
+/// assert_eq!(GridVector::SOUTH_WEST * 3, GridVector::new(-3, 3));
+/// ```
 #[derive(
     Debug,
     Clone,
@@ -13,7 +35,11 @@
     derive_more::AddAssign,
     derive_more::Sub,
     derive_more::SubAssign,
+    derive_more::Neg,
+    derive_more::Mul,
 )]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(from = "(i8, i8)", into = "(i8, i8)"))]
 #[display("({x:+}, {y:+})")]
 pub struct GridVector {
     /// The horizontal component.
@@ -43,17 +69,184 @@ impl GridVector {
     /// The Southwest unit vector.
     pub const SOUTH_WEST: Self = Self::new(-1, 1);
 
+    /// The four cardinal unit vectors, in clockwise order starting from North:
+    /// [`NORTH`](Self::NORTH), [`EAST`](Self::EAST), [`SOUTH`](Self::SOUTH), [`WEST`](Self::WEST).
+    pub const ALL_CARDINAL: [Self; 4] = [Self::NORTH, Self::EAST, Self::SOUTH, Self::WEST];
+
+    /// All eight unit vectors, in clockwise order starting from North:
+    /// [`NORTH`](Self::NORTH), [`NORTH_EAST`](Self::NORTH_EAST), [`EAST`](Self::EAST),
+    /// [`SOUTH_EAST`](Self::SOUTH_EAST), [`SOUTH`](Self::SOUTH), [`SOUTH_WEST`](Self::SOUTH_WEST),
+    /// [`WEST`](Self::WEST), [`NORTH_WEST`](Self::NORTH_WEST).
+    pub const ALL_OCTILE: [Self; 8] = [
+        Self::NORTH,
+        Self::NORTH_EAST,
+        Self::EAST,
+        Self::SOUTH_EAST,
+        Self::SOUTH,
+        Self::SOUTH_WEST,
+        Self::WEST,
+        Self::NORTH_WEST,
+    ];
+
     /// Scales the vector by a factor.
     #[must_use]
     pub const fn scale(self, factor: i8) -> Self {
         Self::new(self.x * factor, self.y * factor)
     }
 
+    /// Returns the displacement from `from` to `to`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use grid_mask::{GridPoint, GridVector};
+    /// let vec = GridVector::from_points(GridPoint::ORIGIN, GridPoint::MAX);
+    /// assert_eq!(vec, GridVector::new(7, 7));
+    /// ```
+    #[must_use]
+    pub const fn from_points(from: GridPoint, to: GridPoint) -> Self {
+        // because GridPos is bounded to 0..=7, a cast to i8 is safe
+        let x = to.x().get().cast_signed() - from.x().get().cast_signed();
+        let y = to.y().get().cast_signed() - from.y().get().cast_signed();
+
+        Self::new(x, y)
+    }
+
+    /// Returns `true` if exactly one component is non-zero.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use grid_mask::GridVector;
+    /// assert!(GridVector::new(0, -3).is_cardinal());
+    /// assert!(!GridVector::NORTH_EAST.is_cardinal());
+    /// assert!(!GridVector::ZERO.is_cardinal());
+    /// ```
+    #[must_use]
+    pub const fn is_cardinal(&self) -> bool {
+        (self.x == 0) != (self.y == 0)
+    }
+
+    /// Returns `true` if both components are non-zero.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use grid_mask::GridVector;
+    /// assert!(GridVector::new(2, -3).is_diagonal());
+    /// assert!(!GridVector::EAST.is_diagonal());
+    /// ```
+    #[must_use]
+    pub const fn is_diagonal(&self) -> bool {
+        self.x != 0 && self.y != 0
+    }
+
+    /// Returns `true` if both components are in `-1..=1`.
+    ///
+    /// Note that this includes [`GridVector::ZERO`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use grid_mask::GridVector;
+    /// assert!(GridVector::SOUTH_WEST.is_unit());
+    /// assert!(!GridVector::new(2, 0).is_unit());
+    /// ```
+    #[must_use]
+    pub const fn is_unit(&self) -> bool {
+        self.x.unsigned_abs() <= 1 && self.y.unsigned_abs() <= 1
+    }
+
+    /// Rotates the vector 90° clockwise, `(x, y) → (-y, x)`.
+    ///
+    /// # Panics
+    ///
+    /// In debug builds, panics if `y` is [`i8::MIN`], following the overflow behavior of
+    /// [`i8`] negation. Release builds wrap instead.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use grid_mask::GridVector;
+    /// assert_eq!(GridVector::NORTH.rotate_90(), GridVector::EAST);
+    /// assert_eq!(GridVector::new(2, 1).rotate_90(), GridVector::new(-1, 2));
+    /// ```
+    #[must_use]
+    pub const fn rotate_90(&self) -> Self {
+        Self::new(-self.y, self.x)
+    }
+
+    /// Rotates the vector 180°, `(x, y) → (-x, -y)`.
+    ///
+    /// # Panics
+    ///
+    /// In debug builds, panics if either component is [`i8::MIN`], following the overflow
+    /// behavior of [`i8`] negation. Release builds wrap instead.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use grid_mask::GridVector;
+    /// assert_eq!(GridVector::NORTH_EAST.rotate_180(), GridVector::SOUTH_WEST);
+    /// ```
+    #[must_use]
+    pub const fn rotate_180(&self) -> Self {
+        Self::new(-self.x, -self.y)
+    }
+
+    /// Rotates the vector 270° clockwise (90° counter-clockwise), `(x, y) → (y, -x)`.
+    ///
+    /// # Panics
+    ///
+    /// In debug builds, panics if `x` is [`i8::MIN`], following the overflow behavior of
+    /// [`i8`] negation. Release builds wrap instead.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use grid_mask::GridVector;
+    /// assert_eq!(GridVector::NORTH.rotate_270(), GridVector::WEST);
+    /// ```
+    #[must_use]
+    pub const fn rotate_270(&self) -> Self {
+        Self::new(self.y, -self.x)
+    }
+
     /// Returns the Manhattan distance between the vector and the origin.
     #[must_use]
     pub const fn manhattan_distance(&self) -> u8 {
         self.x.unsigned_abs() + self.y.unsigned_abs()
     }
+
+    /// Returns the Manhattan (taxicab) magnitude of the vector, `|x| + |y|`.
+    ///
+    /// Saturates at [`u8::MAX`] if the magnitude does not fit in a [`u8`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use grid_mask::GridVector;
+    /// assert_eq!(GridVector::new(-3, 2).manhattan_magnitude(), 5);
+    /// assert_eq!(GridVector::new(i8::MIN, i8::MIN).manhattan_magnitude(), u8::MAX);
+    /// ```
+    #[must_use]
+    pub const fn manhattan_magnitude(&self) -> u8 {
+        self.x.unsigned_abs().saturating_add(self.y.unsigned_abs())
+    }
+
+    /// Returns the Chebyshev (king-move) magnitude of the vector, `max(|x|, |y|)`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use grid_mask::GridVector;
+    /// assert_eq!(GridVector::new(-3, 2).chebyshev_magnitude(), 3);
+    /// ```
+    #[must_use]
+    pub const fn chebyshev_magnitude(&self) -> u8 {
+        let (x, y) = (self.x.unsigned_abs(), self.y.unsigned_abs());
+        if x > y { x } else { y }
+    }
 }
 
 impl From<(i8, i8)> for GridVector {
@@ -0,0 +1,40 @@
+use std::marker::PhantomData;
+
+use crate::Adjacency;
+use crate::grid::GridMask;
+use crate::num::BitIndexU64;
+
+/// An iterator over the connected components of a [`GridMask`].
+///
+/// # Type Parameters
+///
+/// * `A` - The [`Adjacency`] strategy used to connect cells.
+#[derive(Debug, Clone)]
+pub struct ComponentIter<A> {
+    remaining: GridMask,
+    _adj: PhantomData<A>,
+}
+
+impl<A: Adjacency> ComponentIter<A> {
+    pub(crate) const fn new(mask: GridMask) -> Self {
+        Self { remaining: mask, _adj: PhantomData }
+    }
+}
+
+impl<A: Adjacency> Iterator for ComponentIter<A> {
+    type Item = GridMask;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let seed = BitIndexU64::from_first_set(self.remaining.0)?;
+        let component = self.remaining.contiguous::<A>(seed);
+        self.remaining &= !component;
+        Some(component)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let count = self.remaining.count();
+        (count.min(1), Some(count))
+    }
+}
+
+impl<A: Adjacency> std::iter::FusedIterator for ComponentIter<A> {}
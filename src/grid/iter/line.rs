@@ -0,0 +1,66 @@
+use std::iter::FusedIterator;
+
+use crate::GridPoint;
+
+/// An iterator over the [`GridPoint`]s on a line between two points, including both
+/// endpoints.
+///
+/// The line is rasterized using Bresenham's algorithm.
+#[derive(Debug, Clone)]
+pub struct LinePoints {
+    /// The next point to yield, or [`None`] once the end has been yielded.
+    next: Option<(i8, i8)>,
+    end: (i8, i8),
+    delta: (i8, i8),
+    step: (i8, i8),
+    err: i8,
+}
+
+impl LinePoints {
+    pub(crate) const fn new(start: GridPoint, end: GridPoint) -> Self {
+        // because GridPos is bounded to 0..=7, a cast to i8 is safe
+        let (x0, y0) = (start.x().get().cast_signed(), start.y().get().cast_signed());
+        let (x1, y1) = (end.x().get().cast_signed(), end.y().get().cast_signed());
+
+        let (dx, dy) = ((x1 - x0).abs(), -(y1 - y0).abs());
+        let step = ((x1 - x0).signum(), (y1 - y0).signum());
+
+        Self { next: Some((x0, y0)), end: (x1, y1), delta: (dx, dy), step, err: dx + dy }
+    }
+}
+
+impl Iterator for LinePoints {
+    type Item = GridPoint;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (x, y) = self.next?;
+
+        self.next = match (x, y) {
+            current if current == self.end => None,
+            (mut x, mut y) => {
+                let err2 = 2 * self.err;
+                if err2 >= self.delta.1 {
+                    self.err += self.delta.1;
+                    x += self.step.0;
+                }
+                if err2 <= self.delta.0 {
+                    self.err += self.delta.0;
+                    y += self.step.1;
+                }
+                Some((x, y))
+            }
+        };
+
+        Some(GridPoint::new_unchecked(x.cast_unsigned(), y.cast_unsigned()))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // every step advances along the major axis, so the remaining length is the larger
+        // of the remaining distances
+        let len = self.next.map_or(0, |(x, y)| usize::from(x.abs_diff(self.end.0).max(y.abs_diff(self.end.1))) + 1);
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for LinePoints {}
+impl FusedIterator for LinePoints {}
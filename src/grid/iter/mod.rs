@@ -1,7 +1,13 @@
 mod cells;
+mod components;
+mod line;
 mod points;
+mod rect_points;
 mod spaces;
 
 pub use cells::Cells;
+pub use components::ComponentIter;
+pub use line::LinePoints;
 pub use points::Points;
+pub use rect_points::RectPoints;
 pub use spaces::Spaces;
@@ -0,0 +1,46 @@
+use std::ops::Range;
+
+use crate::{GridPoint, GridRect};
+
+/// An iterator over all [`GridPoint`]s of a [`GridRect`], in row-major order.
+#[derive(Debug, Clone)]
+pub struct RectPoints {
+    rect: GridRect,
+    /// The offsets, in row-major order within the rectangle, not yet yielded.
+    remaining: Range<u8>,
+}
+
+impl RectPoints {
+    pub(crate) const fn new(rect: GridRect) -> Self {
+        Self { rect, remaining: 0..rect.w().get() * rect.h().get() }
+    }
+
+    fn point_at(&self, offset: u8) -> GridPoint {
+        let width = self.rect.w().get();
+        let x = self.rect.x().get() + offset % width;
+        let y = self.rect.y().get() + offset / width;
+        // offset is less than w * h, so the point is within the rectangle
+        GridPoint::new_unchecked(x, y)
+    }
+}
+
+impl Iterator for RectPoints {
+    type Item = GridPoint;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.remaining.next().map(|offset| self.point_at(offset))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.remaining.size_hint()
+    }
+}
+
+impl DoubleEndedIterator for RectPoints {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.remaining.next_back().map(|offset| self.point_at(offset))
+    }
+}
+
+impl ExactSizeIterator for RectPoints {}
+impl std::iter::FusedIterator for RectPoints {}
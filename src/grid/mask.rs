@@ -1,3 +1,5 @@
+use std::cmp::Reverse;
+use std::iter::FusedIterator;
 use std::ops::Range;
 use std::str::FromStr;
 
@@ -6,13 +8,13 @@ use fluent_result::into::{IntoOption, IntoResult};
 use itertools::Itertools;
 use tap::{Conv, Pipe, TryConv};
 
-use crate::err::PatternError;
+use crate::err::{ParseError, PatternError};
 use crate::ext::NotWhitespace;
 use crate::ext::bits::{BitZeros, FromBitRange, OccupiedBitSpan};
 use crate::ext::range::RangeLength;
-use crate::grid::{Cells, Points, Spaces};
+use crate::grid::{Cells, ComponentIter, Points, Spaces};
 use crate::num::{BitIndexU8, BitIndexU64, GridLen, GridPos, SignedMag, VecMagU64};
-use crate::{Adjacency, GridDelta, GridPoint, GridRect, GridSize, GridVector};
+use crate::{Adjacency, Cardinal, GridDelta, GridPoint, GridRect, GridSize, GridVector};
 
 /// An immutable mask of cells on a 8x8 grid.
 #[derive(
@@ -69,264 +71,2068 @@ impl GridMask {
     /// A full mask.
     pub const FULL: Self = Self(u64::MAX);
 
+    /// A mask of the cells on the outer edge of the grid.
+    pub const GRID_BOUNDARY: Self = Self(0xFF81_8181_8181_81FF);
+    /// A mask of the cells not on the outer edge of the grid.
+    pub const GRID_INTERIOR: Self = Self(!Self::GRID_BOUNDARY.0);
+
+    /// A mask of the cells `(i, i)`, running from `(0, 0)` to `(7, 7)`.
+    ///
+    /// ```text
+    /// # . . . . . . .
+    /// . # . . . . . .
+    /// . . # . . . . .
+    /// . . . # . . . .
+    /// . . . . # . . .
+    /// . . . . . # . .
+    /// . . . . . . # .
+    /// . . . . . . . #
+    /// ```
+    pub const MAIN_DIAGONAL: Self = Self(0x8040_2010_0804_0201);
+    /// A mask of the cells `(7 - i, i)`, running from `(7, 0)` to `(0, 7)`.
+    ///
+    /// ```text
+    /// . . . . . . . #
+    /// . . . . . . # .
+    /// . . . . . # . .
+    /// . . . . # . . .
+    /// . . . # . . . .
+    /// . . # . . . . .
+    /// . # . . . . . .
+    /// # . . . . . . .
+    /// ```
+    pub const ANTI_DIAGONAL: Self = Self(0x0102_0408_1020_4080);
+
     /// The number of rows in the mask.
     pub const ROWS: GridLen = GridLen::const_new::<8>();
     /// The number of columns in the mask.
     pub const COLS: GridLen = GridLen::const_new::<8>();
 
+    /// A bitmask of the first row.
+    pub(crate) const ROW_FIRST: u64 = 0xFF;
     /// A bitmask of the first column.
     pub(crate) const COL_FIRST: u64 = 0x0101_0101_0101_0101;
 
-    /// Returns the number of set cells.
-    #[must_use]
-    pub const fn count(&self) -> usize {
-        self.0.count_ones() as usize
-    }
-
-    /// Returns the state of the cell at `index`.
-    pub fn get<Idx: Into<BitIndexU64>>(&self, index: Idx) -> bool {
-        (*self & index.into().conv::<Self>()) != Self::EMPTY
-    }
-
-    /// Updates the cell at `index` to `value`.
-    pub fn update<Idx: Into<BitIndexU64>>(&mut self, index: Idx, value: bool) {
-        *self = self.with(index, value);
-    }
-
-    /// Returns a new mask with the cell at `index` set to `value`.
+    /// Creates a new [`GridMask`] by calling `f` with the coordinates of each cell.
+    ///
+    /// Cells are visited in row-major order, and are set if `f` returns `true`.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - A predicate taking the `x` and `y` coordinates of a cell.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use grid_mask::GridMask;
+    /// let diagonal = GridMask::from_fn(|x, y| x == y);
+    /// assert_eq!(diagonal.count(), 8);
+    ///
+    /// let checkerboard = GridMask::from_fn(|x, y| (x + y) % 2 == 0);
+    /// assert_eq!(checkerboard.count(), 32);
+    /// ```
     #[must_use]
-    pub fn with<Idx: Into<BitIndexU64>>(self, index: Idx, value: bool) -> Self {
-        if value { self.const_set::<true>(index.into()) } else { self.const_set::<false>(index.into()) }
+    pub fn from_fn(f: impl Fn(u8, u8) -> bool) -> Self {
+        GridPoint::all_values().filter(|point| f(point.x().get(), point.y().get())).collect()
     }
 
-    /// Sets a new mask with the cell at `index` set to `value`.
+    /// Creates a new [`GridMask`] of the cells on the line from `start` to `end`.
+    ///
+    /// The line is rasterized using Bresenham's algorithm, and includes both endpoints.
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - The first endpoint of the line.
+    /// * `end` - The second endpoint of the line.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use grid_mask::{GridMask, GridPoint};
+    /// let diagonal = GridMask::line(GridPoint::ORIGIN, GridPoint::MAX);
+    ///
+    /// assert_eq!(diagonal, GridMask::from_fn(|x, y| x == y));
+    /// ```
     #[must_use]
-    const fn const_set<const VALUE: bool>(self, index: BitIndexU64) -> Self {
-        match (VALUE, 1 << index.get()) {
-            (true, bit) => Self(self.0 | bit),
-            (false, bit) => Self(self.0 & !bit),
-        }
+    pub fn line(start: GridPoint, end: GridPoint) -> Self {
+        start.all_between(end).collect()
     }
 
-    const COLS_U32: u32 = 8;
-
-    /// Returns a new mask translated by `delta`.
+    /// Returns `true` if the line from `from` to `to` does not pass through any cell set in
+    /// `obstacles`.
+    ///
+    /// The line is rasterized as in [`line`](Self::line). The endpoints themselves are not
+    /// checked, so an obstacle at `from` or `to` does not block the line. See
+    /// [`line_of_sight_inclusive`](Self::line_of_sight_inclusive) to also check the endpoints.
+    ///
+    /// # Arguments
+    ///
+    /// * `from` - The first endpoint of the line.
+    /// * `to` - The second endpoint of the line.
+    /// * `obstacles` - The cells that block the line.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use grid_mask::{GridMask, GridPoint};
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let obstacles = GridMask::from(GridPoint::try_new(3, 0)?);
+    ///
+    /// assert!(!GridMask::line_of_sight(GridPoint::ORIGIN, GridPoint::try_new(7, 0)?, obstacles));
+    /// assert!(GridMask::line_of_sight(GridPoint::ORIGIN, GridPoint::try_new(3, 0)?, obstacles));
+    /// assert!(GridMask::line_of_sight(GridPoint::ORIGIN, GridPoint::try_new(0, 7)?, obstacles));
+    /// # Ok(())
+    /// # }
+    /// ```
     #[must_use]
-    pub fn translate(&self, delta: GridVector) -> Self {
-        delta
-            .try_conv::<GridDelta<VecMagU64>>()
-            .map_or(0, |delta| {
-                let data = self.0;
-
-                let data_shifted_y = match delta.y {
-                    SignedMag::Positive(dy) => data << (dy.get().conv::<u32>() * Self::COLS_U32),
-                    SignedMag::Negative(dy) => data >> (dy.get().conv::<u32>() * Self::COLS_U32),
-                    SignedMag::Zero => data,
-                };
-
-                match delta.x {
-                    SignedMag::Positive(dx) => {
-                        let mask_shifted_x_y = data_shifted_y << dx.get();
-
-                        let col_mask = u8::from_bit_range(..dx).conv::<u64>() * Self::COL_FIRST;
-
-                        mask_shifted_x_y & !col_mask
-                    }
-                    SignedMag::Negative(dx) => {
-                        let col_mask = u8::from_bit_range(..dx).conv::<u64>() * Self::COL_FIRST;
-                        (data_shifted_y & !col_mask) >> dx.get()
-                    }
-                    SignedMag::Zero => data_shifted_y,
-                }
-            })
-            .pipe(Self)
+    pub fn line_of_sight(from: GridPoint, to: GridPoint, obstacles: Self) -> bool {
+        let endpoints = Self::from(from) | Self::from(to);
+        !Self::line(from, to).difference(endpoints).overlaps(obstacles)
     }
 
-    /// Returns `true` if the mask is [`EMPTY`](Self::EMPTY).
+    /// Returns `true` if the line from `from` to `to`, including its endpoints, does not pass
+    /// through any cell set in `obstacles`.
+    ///
+    /// # Arguments
+    ///
+    /// * `from` - The first endpoint of the line.
+    /// * `to` - The second endpoint of the line.
+    /// * `obstacles` - The cells that block the line.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use grid_mask::{GridMask, GridPoint};
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let obstacles = GridMask::from(GridPoint::try_new(3, 0)?);
+    ///
+    /// assert!(!GridMask::line_of_sight_inclusive(GridPoint::ORIGIN, GridPoint::try_new(3, 0)?, obstacles));
+    /// assert!(GridMask::line_of_sight_inclusive(GridPoint::ORIGIN, GridPoint::try_new(2, 0)?, obstacles));
+    /// # Ok(())
+    /// # }
+    /// ```
     #[must_use]
-    pub const fn is_empty(&self) -> bool {
-        self.0 == 0
+    pub fn line_of_sight_inclusive(from: GridPoint, to: GridPoint, obstacles: Self) -> bool {
+        !Self::line(from, to).overlaps(obstacles)
     }
 
-    /// Returns `true` if the mask is [`FULL`](Self::FULL).
+    /// Creates a new [`GridMask`] of the cells at exactly Manhattan distance `radius`
+    /// from `center`.
+    ///
+    /// Cells that would fall outside the grid are clipped.
+    ///
+    /// # Arguments
+    ///
+    /// * `center` - The center of the diamond.
+    /// * `radius` - The Manhattan distance of the set cells from `center`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use grid_mask::{GridMask, GridPoint};
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let diamond = GridMask::diamond(GridPoint::try_new(4, 4)?, 1);
+    ///
+    /// assert_eq!(diamond.count(), 4);
+    /// assert!(!diamond.get(GridPoint::try_new(4, 4)?));
+    /// # Ok(())
+    /// # }
+    /// ```
     #[must_use]
-    pub const fn is_full(&self) -> bool {
-        self.0 == u64::MAX
+    pub fn diamond(center: GridPoint, radius: u8) -> Self {
+        radius
+            .checked_sub(1)
+            .map_or_else(|| Self::from(center), |inner| Self::disk(center, radius) & !Self::disk(center, inner))
     }
 
-    /// Returns an iterator over all cells of the mask.
+    /// Creates a new [`GridMask`] of the cells within Manhattan distance `radius`
+    /// of `center`.
     ///
-    /// Iterates from the top-left cell (`(0, 0)`) to the bottom-right cell
-    /// (`(7, 7)`).
+    /// Cells that would fall outside the grid are clipped.
+    ///
+    /// # Arguments
+    ///
+    /// * `center` - The center of the disk.
+    /// * `radius` - The maximum Manhattan distance of the set cells from `center`.
     ///
     /// # Examples
     ///
     /// ```rust
-    /// # use grid_mask::GridMask;
-    /// let mask = GridMask(0b101);
-    ///
-    /// let mut cells = mask.cells();
+    /// # use grid_mask::{GridMask, GridPoint};
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let disk = GridMask::disk(GridPoint::try_new(4, 4)?, 1);
     ///
-    /// assert_eq!(cells.next(), Some(true));
-    /// assert_eq!(cells.next(), Some(false));
-    /// assert_eq!(cells.next(), Some(true));
-    /// assert_eq!(cells.nth(60), Some(false));
+    /// assert_eq!(disk.count(), 5);
+    /// assert!(disk.get(GridPoint::try_new(4, 4)?));
+    /// # Ok(())
+    /// # }
     /// ```
     #[must_use]
-    pub const fn cells(&self) -> Cells<'_> {
-        Cells::new(self)
+    pub fn disk(center: GridPoint, radius: u8) -> Self {
+        Self::from(center).grow_n::<Cardinal>(radius.into())
     }
 
-    /// Returns a [`GridMask`] of all points connected to `seed` within the current mask
-    /// using the provided [`Adjacency`].
+    /// Creates a new [`GridMask`] from row-major bytes.
     ///
-    /// # Arguments
+    /// Each byte is one row, starting with row `0`. Within a row, bit `0` is column `0`.
+    /// This is the inverse of [`to_bytes`](Self::to_bytes).
     ///
-    /// * `seed` - The starting point for the flood fill.
+    /// # Examples
     ///
-    /// # Type Parameters
+    /// ```rust
+    /// # use grid_mask::GridMask;
+    /// assert_eq!(GridMask::from_bytes([0xFF; 8]), GridMask::FULL);
+    /// assert_eq!(GridMask::from_bytes([1, 0, 0, 0, 0, 0, 0, 0]), GridMask(1));
+    /// ```
+    #[must_use]
+    pub const fn from_bytes(bytes: [u8; 8]) -> Self {
+        Self(u64::from_le_bytes(bytes))
+    }
+
+    /// Returns the mask as row-major bytes.
     ///
-    /// * `A` - The [`Adjacency`] strategy to use.
+    /// Each byte is one row, starting with row `0`. Within a row, bit `0` is column `0`.
+    /// This is the inverse of [`from_bytes`](Self::from_bytes).
     ///
     /// # Examples
     ///
     /// ```rust
-    /// # use std::error::Error;
-    /// # fn main() -> Result<(), Box<dyn Error>> {
-    /// # use grid_mask::{GridPoint, GridMask, GridRect, Cardinal};
-    /// let mask: GridMask = GridRect::new((0, 0), (2, 2))?.into();
-    /// let connected = mask.contiguous::<Cardinal>(GridPoint::ORIGIN);
-    /// assert_eq!(connected, mask);
-    /// # Ok(())
-    /// # }
+    /// # use grid_mask::{GridMask, GridPoint};
+    /// let mask = GridMask::from(GridPoint::const_new::<2, 1>());
+    ///
+    /// assert_eq!(mask.to_bytes(), [0, 0b100, 0, 0, 0, 0, 0, 0]);
     /// ```
     #[must_use]
-    pub fn contiguous<A: Adjacency>(self, seed: impl Into<BitIndexU64>) -> Self {
-        match seed.into().conv::<Self>() & self {
-            connected if connected.is_empty() => Self::EMPTY,
-            mut connected => loop {
-                match A::connected(connected) & self {
-                    grown if grown == connected => break connected,
-                    grown => connected = grown,
-                }
-            },
-        }
+    pub const fn to_bytes(&self) -> [u8; 8] {
+        self.0.to_le_bytes()
     }
 
-    /// Returns a [`GridMask`] of all points connected to `seed` within the current mask
-    /// using the provided [`Adjacency`].
-    ///
-    /// # Arguments
+    /// Creates a new [`GridMask`] from an array of rows.
     ///
-    /// * `seed` - The starting point for the flood fill.
+    /// `rows[y]` is row `y`, and bit `x` of each row is column `x`, matching the layout
+    /// of a little-endian rank-file bitboard. This is equivalent to
+    /// [`from_bytes`](Self::from_bytes), and the inverse of [`to_row_array`](Self::to_row_array).
     ///
-    /// # Type Parameters
+    /// # Arguments
     ///
-    /// * `A` - The [`Adjacency`] strategy to use.
+    /// * `rows` - The bit pattern of each row.
     ///
     /// # Examples
     ///
     /// ```rust
-    /// # use std::error::Error;
-    /// # fn main() -> Result<(), Box<dyn Error>> {
-    /// # use grid_mask::{GridPoint, GridMask, GridRect, Cardinal};
-    /// let mask: GridMask = GridRect::new((0, 0), (2, 2))?.into();
-    /// let connected = mask.contiguous::<Cardinal>(GridPoint::ORIGIN);
-    /// assert_eq!(connected, mask);
-    /// # Ok(())
-    /// # }
+    /// # use grid_mask::{GridMask, GridPoint};
+    /// assert_eq!(GridMask::from_row_array([0xFF; 8]), GridMask::FULL);
+    /// assert_eq!(GridMask::from_row_array([0, 0b100, 0, 0, 0, 0, 0, 0]), GridMask::from(GridPoint::const_new::<2, 1>()));
     /// ```
     #[must_use]
-    pub fn grow<A: Adjacency>(self) -> Self {
-        A::connected(self)
+    pub const fn from_row_array(rows: [u8; 8]) -> Self {
+        Self::from_bytes(rows)
     }
 
-    /// Returns an iterator over the positions of all set cells of the mask.
+    /// Returns the mask as an array of rows.
     ///
-    /// Iterates from the top-left cell (`(0, 0)`, least significant bit)
-    /// to the bottom-right cell (`(7, 7)`, most significant bit).
+    /// Element `y` is row `y`, and bit `x` of each row is column `x`. This is equivalent to
+    /// [`to_bytes`](Self::to_bytes), and the inverse of [`from_row_array`](Self::from_row_array).
     ///
     /// # Examples
     ///
     /// ```rust
     /// # use grid_mask::{GridMask, GridPoint};
-    /// let mask = GridMask(0b101);
-    /// let points: Vec<_> = mask.points().collect();
+    /// let mask = GridMask::from(GridPoint::const_new::<2, 1>());
     ///
-    /// assert_eq!(points.len(), 2);
-    /// assert_eq!(points[0], (0, 0));
-    /// assert_eq!(points[1], (2, 0));
+    /// assert_eq!(mask.to_row_array(), [0, 0b100, 0, 0, 0, 0, 0, 0]);
     /// ```
     #[must_use]
-    pub fn points(&self) -> Points {
-        Points::new(*self)
+    pub const fn to_row_array(&self) -> [u8; 8] {
+        self.to_bytes()
     }
 
-    /// Returns an iterator over the positions of all unset cells of the mask.
+    /// Returns the mask as an array of cells, where element `i` is `true` iff bit `i` is set.
     ///
-    /// Iterates from the top-left cell (`(0, 0)`, least significant bit)
-    /// to the bottom-right cell (`(7, 7)`, most significant bit).
+    /// This is the inverse of the `From<[bool; 64]>` conversion.
     ///
     /// # Examples
     ///
     /// ```rust
-    /// # use grid_mask::{GridMask, GridPoint};
-    /// let mask = GridMask::FULL.with(GridPoint::ORIGIN, false);
-    /// let spaces: Vec<GridPoint> = mask.spaces().collect();
+    /// # use grid_mask::GridMask;
+    /// assert_eq!(GridMask::EMPTY.to_bool_array(), [false; 64]);
+    /// assert_eq!(GridMask::FULL.to_bool_array(), [true; 64]);
     ///
-    /// assert_eq!(spaces.len(), 1);
-    /// assert_eq!(spaces[0], (0, 0));
+    /// let cells = GridMask(0b101).to_bool_array();
+    /// assert_eq!(cells[..3], [true, false, true]);
     /// ```
     #[must_use]
-    pub fn spaces(&self) -> Spaces {
-        Spaces::new(*self)
+    pub const fn to_bool_array(&self) -> [bool; 64] {
+        let mut cells = [false; 64];
+        let mut i = 0;
+        while i < cells.len() {
+            cells[i] = self.0 & (1 << i) != 0;
+            i += 1;
+        }
+        cells
     }
 
-    /// Returns a bitmask of the columns that are occupied in the mask.
+    /// Returns the runs of consecutive set cells in each row, as `(row, start_col, length)`
+    /// tuples.
+    ///
+    /// Runs are ordered by row, then by starting column. This is the inverse of
+    /// [`run_length_decode`](Self::run_length_decode).
     ///
     /// # Examples
     ///
     /// ```rust
-    /// # use grid_mask::{GridMask, GridPoint};
-    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
-    /// assert_eq!(GridMask::EMPTY.occupied_cols(), 0b0000_0000);
-    /// assert_eq!(GridMask::FULL.occupied_cols(), 0b1111_1111);
-    /// assert_eq!(GridMask(1 | 1 << 63).occupied_cols(), 0b1000_0001);
-    /// assert_eq!(GridMask::try_from(GridPoint::ORIGIN)?.occupied_cols(), 0b0000_0001);
-    /// # Ok(())
-    /// # }
+    /// # use grid_mask::GridMask;
+    /// let mask = GridMask::from_bytes([0b0110_0111, 0, 0, 0, 0, 0, 0, 0xFF]);
+    ///
+    /// assert_eq!(mask.run_length_encode(), [(0, 0, 3), (0, 5, 2), (7, 0, 8)]);
+    /// assert_eq!(GridMask::EMPTY.run_length_encode(), []);
     /// ```
     #[must_use]
-    pub const fn occupied_cols(&self) -> u8 {
-        // Merge the rows upwards
-        let rows_2 = self.0 | (self.0 >> 8);
-        let rows_4 = rows_2 | (rows_2 >> 16);
-        let rows_8 = rows_4 | (rows_4 >> 32);
-        (rows_8 & 0xFF) as u8
+    pub fn run_length_encode(&self) -> Vec<(u8, u8, u8)> {
+        let mut runs = Vec::new();
+        for (row, mut line) in (0..).zip(self.rows_iter()) {
+            while line != 0 {
+                let start = line.trailing_zeros_u8();
+                let len = (!(line >> start)).trailing_zeros_u8();
+                runs.push((row, start, len));
+                line &= u8::MAX.unbounded_shl(u32::from(start + len));
+            }
+        }
+        runs
     }
 
-    /// Returns a bitmask of the rows that are occupied in the mask.
+    /// Creates a new [`GridMask`] from runs of consecutive set cells, given as
+    /// `(row, start_col, length)` tuples.
+    ///
+    /// Cells of a run that fall outside the grid are clipped. This is the inverse of
+    /// [`run_length_encode`](Self::run_length_encode).
+    ///
+    /// # Arguments
+    ///
+    /// * `runs` - The runs of set cells.
     ///
     /// # Examples
     ///
     /// ```rust
     /// # use grid_mask::GridMask;
-    /// assert_eq!(GridMask::EMPTY.occupied_rows(), 0b0000_0000);
-    /// assert_eq!(GridMask::FULL.occupied_rows(), 0b1111_1111);
-    /// assert_eq!(GridMask(1 | 1 << 63).occupied_rows(), 0b1000_0001);
+    /// let mask = GridMask::run_length_decode(&[(0, 0, 3), (0, 5, 2), (7, 0, 8)]);
+    ///
+    /// assert_eq!(mask, GridMask::from_bytes([0b0110_0111, 0, 0, 0, 0, 0, 0, 0xFF]));
+    /// assert_eq!(GridMask::run_length_decode(&[(1, 6, 4)]), GridMask::from_bytes([0, 0b1100_0000, 0, 0, 0, 0, 0, 0]));
     /// ```
     #[must_use]
-    pub const fn occupied_rows(&self) -> u8 {
-        const PACKED_ROWS: u64 = 0x0102_0408_1020_4080;
+    pub fn run_length_decode(runs: &[(u8, u8, u8)]) -> Self {
+        runs.iter()
+            .flat_map(|&(row, start, len)| (start..start.saturating_add(len)).map(move |col| (col, row)))
+            .filter_map(|point| GridPoint::try_from(point).ok())
+            .collect()
+    }
+
+    /// Returns the mask as a 16 character lowercase hexadecimal string.
+    ///
+    /// This is the inverse of [`from_hex`](Self::from_hex).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use grid_mask::GridMask;
+    /// assert_eq!(GridMask::EMPTY.format_hex(), "0000000000000000");
+    /// assert_eq!(GridMask::FULL.format_hex(), "ffffffffffffffff");
+    /// assert_eq!(GridMask(0xAB).format_hex(), "00000000000000ab");
+    /// ```
+    #[must_use]
+    pub fn format_hex(&self) -> String {
+        format!("{:016x}", self.0)
+    }
+
+    /// Parses a 16 character hexadecimal string into a [`GridMask`].
+    ///
+    /// Both lowercase and uppercase digits are accepted. This is the inverse of
+    /// [`format_hex`](Self::format_hex).
+    ///
+    /// # Arguments
+    ///
+    /// * `s` - The hexadecimal string to parse.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// * The string contains a character that is not a hexadecimal digit.
+    /// * The string does not contain exactly 16 characters.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use grid_mask::GridMask;
+    /// # use grid_mask::err::ParseError;
+    /// assert_eq!(GridMask::from_hex("ffffffffffffffff"), Ok(GridMask::FULL));
+    /// assert_eq!(GridMask::from_hex("00000000000000AB"), Ok(GridMask(0xAB)));
+    /// assert_eq!(GridMask::from_hex("ff"), Err(ParseError::WrongLength(2)));
+    /// assert_eq!(GridMask::from_hex("fffffffffffffffg"), Err(ParseError::InvalidChar('g')));
+    /// ```
+    pub fn from_hex(s: &str) -> Result<Self, ParseError> {
+        s.chars()
+            .try_fold((0u64, 0usize), |(value, len), c| {
+                c.to_digit(16).ok_or(ParseError::InvalidChar(c)).map(|digit| ((value << 4) | u64::from(digit), len + 1))
+            })
+            .and_then(|(value, len)| match len {
+                16 => Ok(Self(value)),
+                len => ParseError::WrongLength(len).into_err(),
+            })
+    }
+
+    /// Returns the number of set cells.
+    #[must_use]
+    pub const fn count(&self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    /// Returns the sums of the `x` and `y` coordinates of the set cells, and their count.
+    fn coordinate_sums(self) -> (u16, u16, u16) {
+        self.points().fold((0, 0, 0), |(x, y, count), point| {
+            (x + u16::from(point.x().get()), y + u16::from(point.y().get()), count + 1)
+        })
+    }
+
+    /// Returns the average position of the set cells, or [`None`] if the mask is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use grid_mask::{GridMask, GridPoint};
+    /// assert_eq!(GridMask::FULL.centroid(), Some((3.5, 3.5)));
+    /// assert_eq!(GridMask::from(GridPoint::ORIGIN).centroid(), Some((0.0, 0.0)));
+    /// assert_eq!(GridMask::EMPTY.centroid(), None);
+    /// ```
+    #[must_use]
+    pub fn centroid(&self) -> Option<(f32, f32)> {
+        match self.coordinate_sums() {
+            (_, _, 0) => None,
+            (x, y, count) => Some((f32::from(x) / f32::from(count), f32::from(y) / f32::from(count))),
+        }
+    }
+
+    /// Returns the cell nearest to the average position of the set cells, or [`None`] if
+    /// the mask is empty.
+    ///
+    /// Coordinates exactly halfway between two cells are rounded up.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use grid_mask::{GridMask, GridPoint};
+    /// assert_eq!(GridMask::FULL.centroid_rounded(), Some(GridPoint::const_new::<4, 4>()));
+    /// assert_eq!(GridMask(0b11).centroid_rounded(), Some(GridPoint::const_new::<1, 0>()));
+    /// assert_eq!(GridMask::EMPTY.centroid_rounded(), None);
+    /// ```
+    #[must_use]
+    pub fn centroid_rounded(&self) -> Option<GridPoint> {
+        let round = |sum: u16, count: u16| (2 * sum + count) / (2 * count);
+        match self.coordinate_sums() {
+            (_, _, 0) => None,
+            (x, y, count) => GridPoint::try_new(round(x, count), round(y, count)).ok(),
+        }
+    }
+
+    /// Returns the state of the cell at `index`.
+    pub fn get<Idx: Into<BitIndexU64>>(&self, index: Idx) -> bool {
+        (*self & index.into().conv::<Self>()) != Self::EMPTY
+    }
+
+    /// Updates the cell at `index` to `value`.
+    pub fn update<Idx: Into<BitIndexU64>>(&mut self, index: Idx, value: bool) {
+        *self = self.with(index, value);
+    }
+
+    /// Returns a new mask with the cell at `index` set to `value`.
+    #[must_use]
+    pub fn with<Idx: Into<BitIndexU64>>(self, index: Idx, value: bool) -> Self {
+        if value { self.const_set::<true>(index.into()) } else { self.const_set::<false>(index.into()) }
+    }
+
+    /// Returns a new mask with the cell at `index` flipped.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use grid_mask::{GridMask, GridPoint};
+    /// let toggled = GridMask::EMPTY.toggle(GridPoint::ORIGIN);
+    ///
+    /// assert_eq!(toggled, GridMask::from(GridPoint::ORIGIN));
+    /// assert_eq!(toggled.toggle(GridPoint::ORIGIN), GridMask::EMPTY);
+    /// ```
+    #[must_use]
+    pub fn toggle<Idx: Into<BitIndexU64>>(self, index: Idx) -> Self {
+        self ^ index.into().conv::<Self>()
+    }
+
+    /// Sets a new mask with the cell at `index` set to `value`.
+    #[must_use]
+    const fn const_set<const VALUE: bool>(self, index: BitIndexU64) -> Self {
+        match (VALUE, 1 << index.get()) {
+            (true, bit) => Self(self.0 | bit),
+            (false, bit) => Self(self.0 & !bit),
+        }
+    }
+
+    const COLS_U32: u32 = 8;
+
+    /// Returns a new mask translated by `delta`.
+    #[must_use]
+    pub fn translate(&self, delta: GridVector) -> Self {
+        delta
+            .try_conv::<GridDelta<VecMagU64>>()
+            .map_or(0, |delta| {
+                let data = self.0;
+
+                let data_shifted_y = match delta.y {
+                    SignedMag::Positive(dy) => data << (dy.get().conv::<u32>() * Self::COLS_U32),
+                    SignedMag::Negative(dy) => data >> (dy.get().conv::<u32>() * Self::COLS_U32),
+                    SignedMag::Zero => data,
+                };
+
+                match delta.x {
+                    SignedMag::Positive(dx) => {
+                        let mask_shifted_x_y = data_shifted_y << dx.get();
+
+                        let col_mask = u8::from_bit_range(..dx).conv::<u64>() * Self::COL_FIRST;
+
+                        mask_shifted_x_y & !col_mask
+                    }
+                    SignedMag::Negative(dx) => {
+                        let col_mask = u8::from_bit_range(..dx).conv::<u64>() * Self::COL_FIRST;
+                        (data_shifted_y & !col_mask) >> dx.get()
+                    }
+                    SignedMag::Zero => data_shifted_y,
+                }
+            })
+            .pipe(Self)
+    }
+
+    /// Returns a new mask translated by `delta`, wrapping around the edges of the grid.
+    ///
+    /// Unlike [`translate`](Self::translate), cells shifted past one edge of the grid
+    /// reappear on the opposite edge, as if the grid were a torus.
+    ///
+    /// # Arguments
+    ///
+    /// * `delta` - The vector to translate by.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use grid_mask::{GridMask, GridPoint, GridVector};
+    /// let mask = GridMask::from(GridPoint::ORIGIN);
+    ///
+    /// assert_eq!(mask.translate_wrapping(GridVector::WEST), GridMask::from(GridPoint::const_new::<7, 0>()));
+    /// assert_eq!(mask.translate_wrapping(GridVector::NORTH), GridMask::from(GridPoint::const_new::<0, 7>()));
+    /// assert_eq!(mask.translate_wrapping(GridVector::new(8, -8)), mask);
+    /// ```
+    #[must_use]
+    pub const fn translate_wrapping(&self, delta: GridVector) -> Self {
+        // rem_euclid of 8 is always in 0..8, so the casts are lossless
+        let dx = delta.x.rem_euclid(8).cast_unsigned() as u32;
+        let dy = delta.y.rem_euclid(8).cast_unsigned() as u32;
+
+        // rows are bytes, so wrapping vertically is a rotation of the whole mask
+        let data = self.0.rotate_left(dy * Self::COLS_U32);
+
+        match dx {
+            0 => Self(data),
+            dx => {
+                // the columns that wrap around to the west edge
+                let wrapped_cols = ((1 << dx) - 1) * Self::COL_FIRST;
+                Self(((data << dx) & !wrapped_cols) | ((data >> (Self::COLS_U32 - dx)) & wrapped_cols))
+            }
+        }
+    }
+
+    /// Returns the union of the mask translated by `direction` once, twice, and so on up to
+    /// `distance` times.
+    ///
+    /// The mask itself is not included, unless it overlaps one of its translations. Cells
+    /// translated outside the grid are discarded, as with [`translate`](Self::translate).
+    ///
+    /// # Arguments
+    ///
+    /// * `direction` - The vector to translate by at each step.
+    /// * `distance` - The number of steps to take.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use grid_mask::{GridMask, GridPoint, GridVector};
+    /// let mask = GridMask::from(GridPoint::ORIGIN);
+    ///
+    /// assert_eq!(mask.shadow(GridVector::EAST, 3), GridMask(0b1110));
+    /// assert_eq!(mask.shadow(GridVector::EAST, 0), GridMask::EMPTY);
+    /// ```
+    #[must_use]
+    pub fn shadow(&self, direction: GridVector, distance: u8) -> Self {
+        let (mut shadow, mut shifted) = (Self::EMPTY, *self);
+        for _ in 0..distance {
+            shifted = shifted.translate(direction);
+            if shifted.is_empty() {
+                break;
+            }
+            shadow |= shifted;
+        }
+        shadow
+    }
+
+    /// Returns a new mask flipped along the main diagonal.
+    ///
+    /// The cell at `(x, y)` is moved to `(y, x)`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use grid_mask::{GridMask, GridPoint};
+    /// let mask = GridMask::from(GridPoint::const_new::<3, 0>());
+    ///
+    /// assert_eq!(mask.transpose(), GridMask::from(GridPoint::const_new::<0, 3>()));
+    /// ```
+    #[must_use]
+    pub const fn transpose(&self) -> Self {
+        const K1: u64 = 0x5500_5500_5500_5500;
+        const K2: u64 = 0x3333_0000_3333_0000;
+        const K4: u64 = 0x0F0F_0F0F_0000_0000;
+
+        // swap 4x4 blocks, then 2x2 blocks, then single cells across the diagonal
+        let mut bits = self.0;
+        let t = K4 & (bits ^ (bits << 28));
+        bits ^= t ^ (t >> 28);
+        let t = K2 & (bits ^ (bits << 14));
+        bits ^= t ^ (t >> 14);
+        let t = K1 & (bits ^ (bits << 7));
+        bits ^= t ^ (t >> 7);
+        Self(bits)
+    }
+
+    /// Returns a new mask rotated 90 degrees clockwise.
+    ///
+    /// The cell at `(x, y)` is moved to `(7 - y, x)`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use grid_mask::{GridMask, GridPoint};
+    /// let mask = GridMask::from(GridPoint::ORIGIN);
+    ///
+    /// assert_eq!(mask.rotate_90(), GridMask::from(GridPoint::const_new::<7, 0>()));
+    /// ```
+    #[must_use]
+    pub const fn rotate_90(&self) -> Self {
+        self.transpose().flip_horizontal()
+    }
+
+    /// Returns a new mask rotated 180 degrees.
+    ///
+    /// The cell at `(x, y)` is moved to `(7 - x, 7 - y)`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use grid_mask::{GridMask, GridPoint};
+    /// let mask = GridMask::from(GridPoint::ORIGIN);
+    ///
+    /// assert_eq!(mask.rotate_180(), GridMask::from(GridPoint::MAX));
+    /// ```
+    #[must_use]
+    pub const fn rotate_180(&self) -> Self {
+        Self(self.0.reverse_bits())
+    }
+
+    /// Returns a new mask rotated 270 degrees clockwise (90 degrees counter-clockwise).
+    ///
+    /// The cell at `(x, y)` is moved to `(y, 7 - x)`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use grid_mask::{GridMask, GridPoint};
+    /// let mask = GridMask::from(GridPoint::ORIGIN);
+    ///
+    /// assert_eq!(mask.rotate_270(), GridMask::from(GridPoint::const_new::<0, 7>()));
+    /// ```
+    #[must_use]
+    pub const fn rotate_270(&self) -> Self {
+        self.transpose().flip_vertical()
+    }
+
+    /// Returns a new mask mirrored left-to-right.
+    ///
+    /// The cell at `(x, y)` is moved to `(7 - x, y)`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use grid_mask::{GridMask, GridPoint};
+    /// let mask = GridMask::from(GridPoint::ORIGIN);
+    ///
+    /// assert_eq!(mask.flip_horizontal(), GridMask::from(GridPoint::const_new::<7, 0>()));
+    /// ```
+    #[must_use]
+    pub const fn flip_horizontal(&self) -> Self {
+        // reversing all bits mirrors both axes, swapping the bytes restores the row order
+        Self(self.0.reverse_bits().swap_bytes())
+    }
+
+    /// Returns a new mask mirrored top-to-bottom.
+    ///
+    /// The cell at `(x, y)` is moved to `(x, 7 - y)`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use grid_mask::{GridMask, GridPoint};
+    /// let mask = GridMask::from(GridPoint::ORIGIN);
+    ///
+    /// assert_eq!(mask.flip_vertical(), GridMask::from(GridPoint::const_new::<0, 7>()));
+    /// ```
+    #[must_use]
+    pub const fn flip_vertical(&self) -> Self {
+        Self(self.0.swap_bytes())
+    }
+
+    /// Returns `true` if the mask is unchanged by [`flip_vertical`](Self::flip_vertical),
+    /// i.e. it is symmetric about the horizontal axis.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use grid_mask::GridMask;
+    /// assert!(GridMask::from_bytes([1, 0, 0, 0, 0, 0, 0, 1]).is_symmetric_horizontal());
+    /// assert!(!GridMask::from_bytes([1, 0, 0, 0, 0, 0, 0, 0]).is_symmetric_horizontal());
+    /// ```
+    #[must_use]
+    pub const fn is_symmetric_horizontal(&self) -> bool {
+        self.0 == self.flip_vertical().0
+    }
+
+    /// Returns `true` if the mask is unchanged by [`flip_horizontal`](Self::flip_horizontal),
+    /// i.e. it is symmetric about the vertical axis.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use grid_mask::GridMask;
+    /// assert!(GridMask(0b1000_0001).is_symmetric_vertical());
+    /// assert!(!GridMask(0b0000_0001).is_symmetric_vertical());
+    /// ```
+    #[must_use]
+    pub const fn is_symmetric_vertical(&self) -> bool {
+        self.0 == self.flip_horizontal().0
+    }
+
+    /// Returns `true` if the mask is unchanged by [`transpose`](Self::transpose), i.e. it is
+    /// symmetric about the main diagonal.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use grid_mask::GridMask;
+    /// assert!(GridMask::MAIN_DIAGONAL.is_symmetric_main_diagonal());
+    /// assert!(!GridMask::from_bytes([0b10, 0, 0, 0, 0, 0, 0, 0]).is_symmetric_main_diagonal());
+    /// ```
+    #[must_use]
+    pub const fn is_symmetric_main_diagonal(&self) -> bool {
+        self.0 == self.transpose().0
+    }
+
+    /// Returns `true` if the mask is unchanged by [`rotate_180`](Self::rotate_180).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use grid_mask::GridMask;
+    /// assert!(GridMask::ANTI_DIAGONAL.is_symmetric_rotational_180());
+    /// assert!(!GridMask(0b1).is_symmetric_rotational_180());
+    /// ```
+    #[must_use]
+    pub const fn is_symmetric_rotational_180(&self) -> bool {
+        self.0 == self.rotate_180().0
+    }
+
+    /// Returns `true` if the mask is [`EMPTY`](Self::EMPTY).
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// Returns `true` if the mask is [`FULL`](Self::FULL).
+    #[must_use]
+    pub const fn is_full(&self) -> bool {
+        self.0 == u64::MAX
+    }
+
+    /// Returns `true` if every set cell of this mask is also set in `other`.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The mask to compare against.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use grid_mask::{GridMask, GridPoint};
+    /// let point = GridMask::from(GridPoint::ORIGIN);
+    ///
+    /// assert!(GridMask::EMPTY.is_subset_of(GridMask::FULL));
+    /// assert!(point.is_subset_of(point));
+    /// assert!(!GridMask::FULL.is_subset_of(point));
+    /// ```
+    #[must_use]
+    pub const fn is_subset_of(&self, other: Self) -> bool {
+        self.0 & other.0 == self.0
+    }
+
+    /// Returns `true` if every set cell of `other` is also set in this mask.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The mask to compare against.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use grid_mask::{GridMask, GridPoint};
+    /// let point = GridMask::from(GridPoint::ORIGIN);
+    ///
+    /// assert!(GridMask::FULL.is_superset_of(GridMask::EMPTY));
+    /// assert!(point.is_superset_of(point));
+    /// assert!(!point.is_superset_of(GridMask::FULL));
+    /// ```
+    #[must_use]
+    pub const fn is_superset_of(&self, other: Self) -> bool {
+        other.is_subset_of(*self)
+    }
+
+    /// Returns `true` if this mask and `other` have at least one set cell in common.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The mask to compare against.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use grid_mask::{GridMask, GridPoint};
+    /// let point = GridMask::from(GridPoint::ORIGIN);
+    ///
+    /// assert!(GridMask::FULL.overlaps(point));
+    /// assert!(!GridMask::EMPTY.overlaps(GridMask::FULL));
+    /// ```
+    #[must_use]
+    pub const fn overlaps(&self, other: Self) -> bool {
+        self.0 & other.0 != 0
+    }
+
+    /// Returns a new mask of the cells set in this mask but not in `other`.
+    ///
+    /// Equivalent to `self & !other`.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The mask of cells to remove.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use grid_mask::{GridMask, GridPoint};
+    /// let point = GridMask::from(GridPoint::ORIGIN);
+    ///
+    /// assert_eq!(GridMask::FULL.difference(GridMask::EMPTY), GridMask::FULL);
+    /// assert_eq!(GridMask::FULL.difference(point), !point);
+    /// assert_eq!(point.difference(point), GridMask::EMPTY);
+    /// ```
+    #[must_use]
+    pub const fn difference(&self, other: Self) -> Self {
+        Self(self.0 & !other.0)
+    }
+
+    /// Returns a new mask of the cells set in exactly one of this mask and `other`.
+    ///
+    /// Equivalent to `self ^ other`.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The mask to compare against.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use grid_mask::{GridMask, GridPoint};
+    /// let point = GridMask::from(GridPoint::ORIGIN);
+    ///
+    /// assert_eq!(GridMask::FULL.symmetric_difference(point), !point);
+    /// assert_eq!(point.symmetric_difference(point), GridMask::EMPTY);
+    /// ```
+    #[must_use]
+    pub const fn symmetric_difference(&self, other: Self) -> Self {
+        Self(self.0 ^ other.0)
+    }
+
+    /// Returns the number of cells that differ between this mask and `other`.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The mask to compare against.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use grid_mask::{GridMask, GridPoint};
+    /// let point = GridMask::from(GridPoint::ORIGIN);
+    ///
+    /// assert_eq!(GridMask::EMPTY.hamming_distance(GridMask::FULL), 64);
+    /// assert_eq!(GridMask::EMPTY.hamming_distance(point), 1);
+    /// assert_eq!(point.hamming_distance(point), 0);
+    /// ```
+    #[must_use]
+    pub const fn hamming_distance(&self, other: Self) -> u32 {
+        self.symmetric_difference(other).0.count_ones()
+    }
+
+    /// Returns the fraction of cells that are the same between this mask and `other`.
+    ///
+    /// Ranges from `0.0` (every cell differs) to `1.0` (the masks are equal).
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The mask to compare against.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use grid_mask::GridMask;
+    /// assert_eq!(GridMask::EMPTY.hamming_similarity(GridMask::FULL), 0.0);
+    /// assert_eq!(GridMask::FULL.hamming_similarity(GridMask::FULL), 1.0);
+    /// assert_eq!(GridMask(u64::from(u32::MAX)).hamming_similarity(GridMask::FULL), 0.5);
+    /// ```
+    #[must_use]
+    #[expect(clippy::cast_precision_loss, reason = "hamming distance is at most 64")]
+    pub const fn hamming_similarity(&self, other: Self) -> f32 {
+        1.0 - self.hamming_distance(other) as f32 / 64.0
+    }
+
+    /// Returns an iterator over all cells of the mask.
+    ///
+    /// Iterates from the top-left cell (`(0, 0)`) to the bottom-right cell
+    /// (`(7, 7)`).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use grid_mask::GridMask;
+    /// let mask = GridMask(0b101);
+    ///
+    /// let mut cells = mask.cells();
+    ///
+    /// assert_eq!(cells.next(), Some(true));
+    /// assert_eq!(cells.next(), Some(false));
+    /// assert_eq!(cells.next(), Some(true));
+    /// assert_eq!(cells.nth(60), Some(false));
+    /// ```
+    #[must_use]
+    pub const fn cells(&self) -> Cells<'_> {
+        Cells::new(self)
+    }
+
+    /// Returns a [`GridMask`] of all points connected to `seed` within the current mask
+    /// using the provided [`Adjacency`].
+    ///
+    /// # Arguments
+    ///
+    /// * `seed` - The starting point for the flood fill.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `A` - The [`Adjacency`] strategy to use.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # use grid_mask::{GridPoint, GridMask, GridRect, Cardinal};
+    /// let mask: GridMask = GridRect::new((0, 0), (2, 2))?.into();
+    /// let connected = mask.contiguous::<Cardinal>(GridPoint::ORIGIN);
+    /// assert_eq!(connected, mask);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn contiguous<A: Adjacency>(self, seed: impl Into<BitIndexU64>) -> Self {
+        match seed.into().conv::<Self>() & self {
+            connected if connected.is_empty() => Self::EMPTY,
+            mut connected => loop {
+                match A::connected(connected) & self {
+                    grown if grown == connected => break connected,
+                    grown => connected = grown,
+                }
+            },
+        }
+    }
+
+    /// Returns a [`GridMask`] of all points connected to `seed` within the current mask
+    /// using the provided [`Adjacency`].
+    ///
+    /// # Arguments
+    ///
+    /// * `seed` - The starting point for the flood fill.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `A` - The [`Adjacency`] strategy to use.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # use grid_mask::{GridPoint, GridMask, GridRect, Cardinal};
+    /// let mask: GridMask = GridRect::new((0, 0), (2, 2))?.into();
+    /// let connected = mask.contiguous::<Cardinal>(GridPoint::ORIGIN);
+    /// assert_eq!(connected, mask);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn grow<A: Adjacency>(self) -> Self {
+        A::connected(self)
+    }
+
+    /// Returns a [`GridMask`] grown once using the provided [`Adjacency`], limited to the
+    /// cells set in `limit`.
+    ///
+    /// This is a single step of the flood fill performed by [`contiguous`](Self::contiguous).
+    ///
+    /// # Arguments
+    ///
+    /// * `limit` - The cells the grown mask is allowed to occupy.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `A` - The [`Adjacency`] strategy to use.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use grid_mask::{GridMask, GridPoint, Cardinal};
+    /// let mask = GridMask::from(GridPoint::ORIGIN);
+    /// let limit = GridMask(0xFF);
+    ///
+    /// assert_eq!(mask.grow_bounded::<Cardinal>(limit), GridMask(0b11));
+    /// assert_eq!(mask.grow_bounded::<Cardinal>(GridMask::FULL), mask.grow::<Cardinal>());
+    /// ```
+    #[must_use]
+    pub fn grow_bounded<A: Adjacency>(self, limit: Self) -> Self {
+        self.grow::<A>() & limit
+    }
+
+    /// Returns a [`GridMask`] of the set cells that have no neighbor, under the provided
+    /// [`Adjacency`], outside the current mask.
+    ///
+    /// Cells outside the grid count as outside the mask, so this is equivalent to
+    /// [`erode`](Self::erode).
+    ///
+    /// # Type Parameters
+    ///
+    /// * `A` - The [`Adjacency`] strategy to use.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # use grid_mask::{GridPoint, GridMask, GridRect, Cardinal};
+    /// let mask: GridMask = GridRect::new((2, 2), (3, 3))?.into();
+    ///
+    /// assert_eq!(mask.interior::<Cardinal>(), GridMask::from(GridPoint::try_new(3, 3)?));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn interior<A: Adjacency>(self) -> Self {
+        self.erode::<A>()
+    }
+
+    /// Returns a [`GridMask`] of the cells adjacent to the current mask, under the
+    /// provided [`Adjacency`], that are not themselves set.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `A` - The [`Adjacency`] strategy to use.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use grid_mask::{GridMask, GridPoint, Cardinal};
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mask = GridMask::from(GridPoint::try_new(4, 4)?);
+    /// let frontier = mask.frontier::<Cardinal>();
+    ///
+    /// assert_eq!(frontier.count(), 4);
+    /// assert!(!frontier.get(GridPoint::try_new(4, 4)?));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn frontier<A: Adjacency>(self) -> Self {
+        self.grow::<A>() & !self
+    }
+
+    /// Returns the number of set cells adjacent to `index` under the provided [`Adjacency`].
+    ///
+    /// The cell at `index` itself is not counted.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The cell whose neighbors are counted.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `A` - The [`Adjacency`] strategy to use.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use grid_mask::{GridMask, GridPoint, Cardinal, Octile};
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// assert_eq!(GridMask::FULL.count_neighbors::<Cardinal>(GridPoint::try_new(4, 4)?), 4);
+    /// assert_eq!(GridMask::FULL.count_neighbors::<Octile>(GridPoint::try_new(4, 4)?), 8);
+    /// assert_eq!(GridMask::FULL.count_neighbors::<Cardinal>(GridPoint::ORIGIN), 2);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    #[expect(clippy::cast_possible_truncation, reason = "a cell has at most 8 neighbors")]
+    pub fn count_neighbors<A: Adjacency>(&self, index: impl Into<BitIndexU64>) -> u8 {
+        let cell = index.into().conv::<Self>();
+        (*self & cell.frontier::<A>()).0.count_ones() as u8
+    }
+
+    /// Returns the number of set cells with at least one neighbor, under the provided
+    /// [`Adjacency`], outside the current mask.
+    ///
+    /// Unlike [`frontier`](Self::frontier), which is the set of cells just outside the mask,
+    /// this counts the outermost cells inside the mask. Cells outside the grid count as
+    /// outside the mask.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `A` - The [`Adjacency`] strategy to use.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use grid_mask::{GridMask, GridPoint, Cardinal};
+    /// assert_eq!(GridMask::FULL.perimeter::<Cardinal>(), 28);
+    /// assert_eq!(GridMask::EMPTY.perimeter::<Cardinal>(), 0);
+    /// assert_eq!(GridMask::from(GridPoint::ORIGIN).perimeter::<Cardinal>(), 1);
+    /// ```
+    #[must_use]
+    pub fn perimeter<A: Adjacency>(&self) -> u32 {
+        (*self & !self.interior::<A>()).0.count_ones()
+    }
+
+    /// Returns a [`GridMask`] of the set cells with no set neighbor under the provided
+    /// [`Adjacency`].
+    ///
+    /// # Type Parameters
+    ///
+    /// * `A` - The [`Adjacency`] strategy to use.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use grid_mask::{GridMask, Cardinal, Octile};
+    /// let checkerboard = GridMask::from_fn(|x, y| (x + y) % 2 == 0);
+    ///
+    /// assert_eq!(checkerboard.isolated_cells::<Cardinal>(), checkerboard);
+    /// assert_eq!(checkerboard.isolated_cells::<Octile>(), GridMask::EMPTY);
+    /// ```
+    #[must_use]
+    pub fn isolated_cells<A: Adjacency>(&self) -> Self {
+        let has_neighbor = A::NEIGHBORS.iter().fold(Self::EMPTY, |acc, &vec| acc | self.translate(vec));
+        *self & !has_neighbor
+    }
+
+    /// Returns the number of set cells with no set neighbor under the provided [`Adjacency`].
+    ///
+    /// # Type Parameters
+    ///
+    /// * `A` - The [`Adjacency`] strategy to use.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use grid_mask::{GridMask, Cardinal};
+    /// let checkerboard = GridMask::from_fn(|x, y| (x + y) % 2 == 0);
+    ///
+    /// assert_eq!(checkerboard.count_isolated::<Cardinal>(), 32);
+    /// assert_eq!(GridMask::FULL.count_isolated::<Cardinal>(), 0);
+    /// ```
+    #[must_use]
+    pub fn count_isolated<A: Adjacency>(&self) -> u32 {
+        self.isolated_cells::<A>().0.count_ones()
+    }
+
+    /// Returns a [`GridMask`] grown `n` times using the provided [`Adjacency`].
+    ///
+    /// Equivalent to calling [`grow`](Self::grow) `n` times in sequence. Returns early
+    /// once the mask stops changing.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - The number of times to grow the mask.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `A` - The [`Adjacency`] strategy to use.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use grid_mask::{GridMask, GridPoint, Cardinal};
+    /// let mask = GridMask::from(GridPoint::ORIGIN);
+    ///
+    /// assert_eq!(mask.grow_n::<Cardinal>(0), mask);
+    /// assert_eq!(mask.grow_n::<Cardinal>(2), mask.grow::<Cardinal>().grow::<Cardinal>());
+    /// assert_eq!(mask.grow_n::<Cardinal>(14), GridMask::FULL);
+    /// ```
+    #[must_use]
+    pub fn grow_n<A: Adjacency>(self, n: usize) -> Self {
+        let mut grown = self;
+        for _ in 0..n {
+            match grown.grow::<A>() {
+                next if next == grown => break,
+                next => grown = next,
+            }
+        }
+        grown
+    }
+
+    /// Returns a [`GridMask`] of all cells whose neighbors, under the provided
+    /// [`Adjacency`], are all set in the current mask.
+    ///
+    /// This is the dual of [`grow`](Self::grow). Cells outside the grid are treated
+    /// as unset, so cells on the edge of the grid never survive erosion.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `A` - The [`Adjacency`] strategy to use.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # use grid_mask::{GridPoint, GridMask, GridRect, Cardinal};
+    /// let mask: GridMask = GridRect::new((2, 2), (3, 3))?.into();
+    /// let eroded = mask.erode::<Cardinal>();
+    /// assert_eq!(eroded, GridMask::from(GridPoint::try_new(3, 3)?));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn erode<A: Adjacency>(self) -> Self {
+        !A::connected(!self) & Self::GRID_INTERIOR
+    }
+
+    /// Returns the hit-and-miss transform of the mask.
+    ///
+    /// The result contains every anchor position `p` where `hit`, translated so that its
+    /// origin is at `p`, lies entirely within the mask, and `miss`, translated the same way,
+    /// lies entirely outside it. Structuring elements that would extend past the edge of the
+    /// grid do not fit.
+    ///
+    /// # Arguments
+    ///
+    /// * `hit` - The cells that must be set, relative to the anchor.
+    /// * `miss` - The cells that must be unset, relative to the anchor.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `hit` and `miss` overlap.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use grid_mask::{GridMask, GridPoint, GridRect};
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mask: GridMask = GridRect::new((2, 2), (3, 3))?.into();
+    ///
+    /// // a top-left corner at (1, 1), with unset cells above and to the left
+    /// let hit: GridMask = [(1, 1), (2, 1), (1, 2)].into_iter().map(GridPoint::try_from).collect::<Result<_, _>>()?;
+    /// let miss: GridMask = [(1, 0), (0, 1)].into_iter().map(GridPoint::try_from).collect::<Result<_, _>>()?;
+    ///
+    /// assert_eq!(mask.hit_and_miss(hit, miss), GridMask::from(GridPoint::try_new(1, 1)?));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn hit_and_miss(&self, hit: Self, miss: Self) -> Self {
+        assert!(!hit.overlaps(miss), "hit and miss must not overlap");
+
+        let fits = |mask: Self, element: Self| {
+            element.points().fold(Self::FULL, |anchors, point| {
+                // because GridPos is bounded to 0..=7, a cast to i8 is safe
+                let offset = GridVector::new(-point.x().get().cast_signed(), -point.y().get().cast_signed());
+                anchors & mask.translate(offset)
+            })
+        };
+
+        fits(*self, hit) & fits(!*self, miss)
+    }
+
+    /// Returns an iterator over the connected components of the mask, using the
+    /// provided [`Adjacency`].
+    ///
+    /// Each component is yielded as a separate [`GridMask`]. Components are disjoint,
+    /// and together they cover every set cell of the mask. Components are yielded in
+    /// order of their first cell.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `A` - The [`Adjacency`] strategy to use.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use grid_mask::{GridMask, GridPoint, Cardinal};
+    /// let mask = GridMask::from(GridPoint::ORIGIN) | GridMask::from(GridPoint::MAX);
+    /// let components: Vec<GridMask> = mask.components::<Cardinal>().collect();
+    ///
+    /// assert_eq!(components, [GridMask::from(GridPoint::ORIGIN), GridMask::from(GridPoint::MAX)]);
+    /// ```
+    #[must_use]
+    pub const fn components<A: Adjacency>(&self) -> ComponentIter<A> {
+        ComponentIter::new(*self)
+    }
+
+    /// Returns the number of connected components of the mask, using the provided
+    /// [`Adjacency`].
+    ///
+    /// # Type Parameters
+    ///
+    /// * `A` - The [`Adjacency`] strategy to use.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use grid_mask::{GridMask, Cardinal, Octile};
+    /// let checkerboard = GridMask::from_fn(|x, y| (x + y) % 2 == 0);
+    ///
+    /// assert_eq!(GridMask::EMPTY.count_components::<Cardinal>(), 0);
+    /// assert_eq!(checkerboard.count_components::<Cardinal>(), 32);
+    /// assert_eq!(checkerboard.count_components::<Octile>(), 1);
+    /// ```
+    #[must_use]
+    pub fn count_components<A: Adjacency>(&self) -> usize {
+        self.components::<A>().count()
+    }
+
+    /// Returns the connected component of the mask with the most set cells, using the
+    /// provided [`Adjacency`].
+    ///
+    /// Ties are broken in favor of the component found first. Returns
+    /// [`EMPTY`](Self::EMPTY) if the mask is empty.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `A` - The [`Adjacency`] strategy to use.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # use grid_mask::{GridMask, GridPoint, GridRect, Cardinal};
+    /// let rect: GridMask = GridRect::new((4, 4), (2, 2))?.into();
+    /// let mask = rect | GridMask::from(GridPoint::ORIGIN);
+    ///
+    /// assert_eq!(mask.largest_component::<Cardinal>(), rect);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn largest_component<A: Adjacency>(&self) -> Self {
+        self.components::<A>().min_by_key(|component| Reverse(component.count())).unwrap_or(Self::EMPTY)
+    }
+
+    /// Returns the connected component of the mask with the fewest set cells, using the
+    /// provided [`Adjacency`].
+    ///
+    /// Ties are broken in favor of the component found first. Returns
+    /// [`EMPTY`](Self::EMPTY) if the mask is empty.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `A` - The [`Adjacency`] strategy to use.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # use grid_mask::{GridMask, GridPoint, GridRect, Cardinal};
+    /// let rect: GridMask = GridRect::new((4, 4), (2, 2))?.into();
+    /// let mask = rect | GridMask::from(GridPoint::ORIGIN);
+    ///
+    /// assert_eq!(mask.smallest_component::<Cardinal>(), GridMask::from(GridPoint::ORIGIN));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn smallest_component<A: Adjacency>(&self) -> Self {
+        self.components::<A>().min_by_key(Self::count).unwrap_or(Self::EMPTY)
+    }
+
+    /// Returns an iterator over the positions of all set cells of the mask.
+    ///
+    /// Iterates from the top-left cell (`(0, 0)`, least significant bit)
+    /// to the bottom-right cell (`(7, 7)`, most significant bit).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use grid_mask::{GridMask, GridPoint};
+    /// let mask = GridMask(0b101);
+    /// let points: Vec<_> = mask.points().collect();
+    ///
+    /// assert_eq!(points.len(), 2);
+    /// assert_eq!(points[0], (0, 0));
+    /// assert_eq!(points[1], (2, 0));
+    /// ```
+    #[must_use]
+    pub fn points(&self) -> Points {
+        Points::new(*self)
+    }
+
+    /// Returns an iterator over the positions of all unset cells of the mask.
+    ///
+    /// Iterates from the top-left cell (`(0, 0)`, least significant bit)
+    /// to the bottom-right cell (`(7, 7)`, most significant bit).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use grid_mask::{GridMask, GridPoint};
+    /// let mask = GridMask::FULL.with(GridPoint::ORIGIN, false);
+    /// let spaces: Vec<GridPoint> = mask.spaces().collect();
+    ///
+    /// assert_eq!(spaces.len(), 1);
+    /// assert_eq!(spaces[0], (0, 0));
+    /// ```
+    #[must_use]
+    pub fn spaces(&self) -> Spaces {
+        Spaces::new(*self)
+    }
+
+    /// Returns a new mask containing only the set cells for which `f` returns `true`.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - A predicate taking the position of a set cell.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use grid_mask::GridMask;
+    /// let left = GridMask::FULL.retain(|point| point.x().get() < 4);
+    ///
+    /// assert_eq!(left, GridMask::from_fn(|x, _| x < 4));
+    /// assert_eq!(GridMask::FULL.retain(|_| false), GridMask::EMPTY);
+    /// ```
+    #[must_use]
+    pub fn retain(&self, f: impl Fn(GridPoint) -> bool) -> Self {
+        self.points().filter(|&point| f(point)).collect()
+    }
+
+    /// Returns a new mask containing only the set cells at indexes `offset`, `offset + n`,
+    /// `offset + 2n`, and so on.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - The distance between kept indexes.
+    /// * `offset` - The first kept index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is `0`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use grid_mask::GridMask;
+    /// assert_eq!(GridMask::FULL.every_nth_cell(2, 0), GridMask(0x5555_5555_5555_5555));
+    /// assert_eq!(GridMask::FULL.every_nth_cell(2, 1), GridMask(0xAAAA_AAAA_AAAA_AAAA));
+    /// assert_eq!(GridMask::FULL.every_nth_cell(8, 3), GridMask::FULL.col_mask(3));
+    /// ```
+    #[must_use]
+    pub const fn every_nth_cell(&self, n: u8, offset: u8) -> Self {
+        assert!(n != 0, "n must be non-zero");
+
+        let mut kept = 0;
+        let mut i = offset as u32;
+        while i < u64::BITS {
+            kept |= 1 << i;
+            i += n as u32;
+        }
+        Self(self.0 & kept)
+    }
+
+    /// Returns the set cell with the lowest index, or [`None`] if the mask is empty.
+    ///
+    /// This is the first set cell in row-major order, starting from `(0, 0)`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use grid_mask::{GridMask, GridPoint};
+    /// assert_eq!(GridMask::FULL.min_set_point(), Some(GridPoint::ORIGIN));
+    /// assert_eq!(GridMask(0b1100).min_set_point(), Some(GridPoint::const_new::<2, 0>()));
+    /// assert_eq!(GridMask::EMPTY.min_set_point(), None);
+    /// ```
+    #[must_use]
+    pub fn min_set_point(&self) -> Option<GridPoint> {
+        self.points().next()
+    }
+
+    /// Returns the set cell with the highest index, or [`None`] if the mask is empty.
+    ///
+    /// This is the last set cell in row-major order, ending at `(7, 7)`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use grid_mask::{GridMask, GridPoint};
+    /// assert_eq!(GridMask::FULL.max_set_point(), Some(GridPoint::MAX));
+    /// assert_eq!(GridMask(0b1100).max_set_point(), Some(GridPoint::const_new::<3, 0>()));
+    /// assert_eq!(GridMask::EMPTY.max_set_point(), None);
+    /// ```
+    #[must_use]
+    pub fn max_set_point(&self) -> Option<GridPoint> {
+        self.points().next_back()
+    }
+
+    /// Returns a bitmask of the columns that are occupied in the mask.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use grid_mask::{GridMask, GridPoint};
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// assert_eq!(GridMask::EMPTY.occupied_cols(), 0b0000_0000);
+    /// assert_eq!(GridMask::FULL.occupied_cols(), 0b1111_1111);
+    /// assert_eq!(GridMask(1 | 1 << 63).occupied_cols(), 0b1000_0001);
+    /// assert_eq!(GridMask::try_from(GridPoint::ORIGIN)?.occupied_cols(), 0b0000_0001);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub const fn occupied_cols(&self) -> u8 {
+        // Merge the rows upwards
+        let rows_2 = self.0 | (self.0 >> 8);
+        let rows_4 = rows_2 | (rows_2 >> 16);
+        let rows_8 = rows_4 | (rows_4 >> 32);
+        (rows_8 & 0xFF) as u8
+    }
+
+    /// Returns a bitmask of the rows that are occupied in the mask.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use grid_mask::GridMask;
+    /// assert_eq!(GridMask::EMPTY.occupied_rows(), 0b0000_0000);
+    /// assert_eq!(GridMask::FULL.occupied_rows(), 0b1111_1111);
+    /// assert_eq!(GridMask(1 | 1 << 63).occupied_rows(), 0b1000_0001);
+    /// ```
+    #[must_use]
+    pub const fn occupied_rows(&self) -> u8 {
+        const PACKED_ROWS: u64 = 0x0102_0408_1020_4080;
 
         // Merge bits horizontally within each row (byte)
         let bits_2 = self.0 | (self.0 >> 1);
         let bits_4 = bits_2 | (bits_2 >> 2);
         let bits_8 = bits_4 | (bits_4 >> 4);
 
-        let row_bits = bits_8 & Self::COL_FIRST;
+        let row_bits = bits_8 & Self::COL_FIRST;
+
+        (u64::wrapping_mul(row_bits, PACKED_ROWS) >> 56) as u8
+    }
+
+    /// Returns a bitmask of every cell in `row`, or `0` if `row` is out of range.
+    const fn row_bits(row: u8) -> u64 {
+        match row {
+            0..8 => Self::ROW_FIRST << (row * 8),
+            _ => 0,
+        }
+    }
+
+    /// Returns a bitmask of every cell in `col`, or `0` if `col` is out of range.
+    const fn col_bits(col: u8) -> u64 {
+        match col {
+            0..8 => Self::COL_FIRST << col,
+            _ => 0,
+        }
+    }
+
+    /// Returns a new mask containing only the cells of `row` that are set in this mask.
+    ///
+    /// Returns [`EMPTY`](Self::EMPTY) if `row` is out of range.
+    ///
+    /// # Arguments
+    ///
+    /// * `row` - The row to keep.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use grid_mask::GridMask;
+    /// assert_eq!(GridMask::FULL.row_mask(0), GridMask(0xFF));
+    /// assert_eq!(GridMask::FULL.row_mask(8), GridMask::EMPTY);
+    /// ```
+    #[must_use]
+    pub const fn row_mask(&self, row: u8) -> Self {
+        Self(self.0 & Self::row_bits(row))
+    }
+
+    /// Returns a new mask containing only the cells of `col` that are set in this mask.
+    ///
+    /// Returns [`EMPTY`](Self::EMPTY) if `col` is out of range.
+    ///
+    /// # Arguments
+    ///
+    /// * `col` - The column to keep.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use grid_mask::GridMask;
+    /// assert_eq!(GridMask::FULL.col_mask(0), GridMask(0x0101_0101_0101_0101));
+    /// assert_eq!(GridMask::FULL.col_mask(8), GridMask::EMPTY);
+    /// ```
+    #[must_use]
+    pub const fn col_mask(&self, col: u8) -> Self {
+        Self(self.0 & Self::col_bits(col))
+    }
+
+    /// Returns the number of set cells in `row`.
+    ///
+    /// Returns `0` if `row` is out of range.
+    ///
+    /// # Arguments
+    ///
+    /// * `row` - The row to count.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use grid_mask::GridMask;
+    /// assert_eq!(GridMask::FULL.count_row(0), 8);
+    /// assert_eq!(GridMask::FULL.count_row(8), 0);
+    /// ```
+    #[must_use]
+    pub const fn count_row(&self, row: u8) -> u32 {
+        self.row_mask(row).0.count_ones()
+    }
 
-        (u64::wrapping_mul(row_bits, PACKED_ROWS) >> 56) as u8
+    /// Returns the number of set cells in `col`.
+    ///
+    /// Returns `0` if `col` is out of range.
+    ///
+    /// # Arguments
+    ///
+    /// * `col` - The column to count.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use grid_mask::GridMask;
+    /// assert_eq!(GridMask::FULL.count_col(0), 8);
+    /// assert_eq!(GridMask::FULL.count_col(8), 0);
+    /// ```
+    #[must_use]
+    pub const fn count_col(&self, col: u8) -> u32 {
+        self.col_mask(col).0.count_ones()
+    }
+
+    /// Returns the leftmost set cell in `row`, or [`None`] if `row` has no set cells.
+    ///
+    /// Returns [`None`] if `row` is out of range.
+    ///
+    /// # Arguments
+    ///
+    /// * `row` - The row to scan.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use grid_mask::{GridMask, GridPoint};
+    /// let mask = GridMask::from_bytes([0, 0b0110_0100, 0, 0, 0, 0, 0, 0]);
+    ///
+    /// assert_eq!(mask.first_set_in_row(1), Some(GridPoint::const_new::<2, 1>()));
+    /// assert_eq!(mask.first_set_in_row(0), None);
+    /// ```
+    #[must_use]
+    pub fn first_set_in_row(&self, row: u8) -> Option<GridPoint> {
+        self.row_mask(row).points().next()
+    }
+
+    /// Returns the rightmost set cell in `row`, or [`None`] if `row` has no set cells.
+    ///
+    /// Returns [`None`] if `row` is out of range.
+    ///
+    /// # Arguments
+    ///
+    /// * `row` - The row to scan.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use grid_mask::{GridMask, GridPoint};
+    /// let mask = GridMask::from_bytes([0, 0b0110_0100, 0, 0, 0, 0, 0, 0]);
+    ///
+    /// assert_eq!(mask.last_set_in_row(1), Some(GridPoint::const_new::<6, 1>()));
+    /// assert_eq!(mask.last_set_in_row(0), None);
+    /// ```
+    #[must_use]
+    pub fn last_set_in_row(&self, row: u8) -> Option<GridPoint> {
+        self.row_mask(row).points().next_back()
+    }
+
+    /// Returns the topmost set cell in `col`, or [`None`] if `col` has no set cells.
+    ///
+    /// Returns [`None`] if `col` is out of range.
+    ///
+    /// # Arguments
+    ///
+    /// * `col` - The column to scan.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use grid_mask::{GridMask, GridPoint};
+    /// let mask = GridMask::from_bytes([0, 0b10, 0, 0b10, 0, 0, 0, 0]);
+    ///
+    /// assert_eq!(mask.first_set_in_col(1), Some(GridPoint::const_new::<1, 1>()));
+    /// assert_eq!(mask.first_set_in_col(0), None);
+    /// ```
+    #[must_use]
+    pub fn first_set_in_col(&self, col: u8) -> Option<GridPoint> {
+        self.col_mask(col).points().next()
+    }
+
+    /// Returns the bottommost set cell in `col`, or [`None`] if `col` has no set cells.
+    ///
+    /// Returns [`None`] if `col` is out of range.
+    ///
+    /// # Arguments
+    ///
+    /// * `col` - The column to scan.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use grid_mask::{GridMask, GridPoint};
+    /// let mask = GridMask::from_bytes([0, 0b10, 0, 0b10, 0, 0, 0, 0]);
+    ///
+    /// assert_eq!(mask.last_set_in_col(1), Some(GridPoint::const_new::<1, 3>()));
+    /// assert_eq!(mask.last_set_in_col(0), None);
+    /// ```
+    #[must_use]
+    pub fn last_set_in_col(&self, col: u8) -> Option<GridPoint> {
+        self.col_mask(col).points().next_back()
+    }
+
+    /// Returns a new mask with every cell in `row` set.
+    ///
+    /// Returns the mask unchanged if `row` is out of range.
+    ///
+    /// # Arguments
+    ///
+    /// * `row` - The row to update.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use grid_mask::GridMask;
+    /// assert_eq!(GridMask::EMPTY.set_row(0), GridMask(0xFF));
+    /// ```
+    #[must_use]
+    pub const fn set_row(&self, row: u8) -> Self {
+        Self(self.0 | Self::row_bits(row))
+    }
+
+    /// Returns a new mask with every cell in `row` cleared.
+    ///
+    /// Returns the mask unchanged if `row` is out of range.
+    ///
+    /// # Arguments
+    ///
+    /// * `row` - The row to update.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use grid_mask::GridMask;
+    /// assert_eq!(GridMask::FULL.clear_row(0), !GridMask(0xFF));
+    /// ```
+    #[must_use]
+    pub const fn clear_row(&self, row: u8) -> Self {
+        Self(self.0 & !Self::row_bits(row))
+    }
+
+    /// Returns a new mask with every cell in `row` flipped.
+    ///
+    /// Returns the mask unchanged if `row` is out of range.
+    ///
+    /// # Arguments
+    ///
+    /// * `row` - The row to update.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use grid_mask::{GridMask, GridPoint};
+    /// let mask = GridMask::from(GridPoint::ORIGIN);
+    ///
+    /// assert_eq!(mask.toggle_row(0), GridMask(0xFE));
+    /// ```
+    #[must_use]
+    pub const fn toggle_row(&self, row: u8) -> Self {
+        Self(self.0 ^ Self::row_bits(row))
+    }
+
+    /// Returns a new mask with every cell in `col` set.
+    ///
+    /// Returns the mask unchanged if `col` is out of range.
+    ///
+    /// # Arguments
+    ///
+    /// * `col` - The column to update.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use grid_mask::GridMask;
+    /// assert_eq!(GridMask::EMPTY.set_col(0), GridMask(0x0101_0101_0101_0101));
+    /// ```
+    #[must_use]
+    pub const fn set_col(&self, col: u8) -> Self {
+        Self(self.0 | Self::col_bits(col))
+    }
+
+    /// Returns a new mask with every cell in `col` cleared.
+    ///
+    /// Returns the mask unchanged if `col` is out of range.
+    ///
+    /// # Arguments
+    ///
+    /// * `col` - The column to update.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use grid_mask::GridMask;
+    /// assert_eq!(GridMask::FULL.clear_col(0), !GridMask(0x0101_0101_0101_0101));
+    /// ```
+    #[must_use]
+    pub const fn clear_col(&self, col: u8) -> Self {
+        Self(self.0 & !Self::col_bits(col))
+    }
+
+    /// Returns a new mask with every cell in `col` flipped.
+    ///
+    /// Returns the mask unchanged if `col` is out of range.
+    ///
+    /// # Arguments
+    ///
+    /// * `col` - The column to update.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use grid_mask::{GridMask, GridPoint};
+    /// let mask = GridMask::from(GridPoint::ORIGIN);
+    ///
+    /// assert_eq!(mask.toggle_col(0), GridMask(0x0101_0101_0101_0100));
+    /// ```
+    #[must_use]
+    pub const fn toggle_col(&self, col: u8) -> Self {
+        Self(self.0 ^ Self::col_bits(col))
+    }
+
+    /// Returns a new mask with the cells of `from_row` moved to `to_row`.
+    ///
+    /// The previous contents of `to_row` are overwritten, and `from_row` is cleared. Returns
+    /// the mask unchanged if `from_row` and `to_row` are equal, or if either is out of range.
+    ///
+    /// # Arguments
+    ///
+    /// * `from_row` - The row to move.
+    /// * `to_row` - The row to move `from_row` to.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use grid_mask::GridMask;
+    /// let mask = GridMask::from_bytes([0, 0b101, 0b11, 0, 0, 0, 0, 0]);
+    ///
+    /// assert_eq!(mask.shift_row(1, 2), GridMask::from_bytes([0, 0, 0b101, 0, 0, 0, 0, 0]));
+    /// assert_eq!(mask.shift_row(1, 8), mask);
+    /// ```
+    #[must_use]
+    pub const fn shift_row(&self, from_row: u8, to_row: u8) -> Self {
+        match (from_row, to_row) {
+            (0..8, 0..8) if from_row != to_row => {
+                let cleared = self.0 & !Self::row_bits(from_row) & !Self::row_bits(to_row);
+                let moved = (self.0 >> (from_row * 8) & Self::ROW_FIRST) << (to_row * 8);
+                Self(cleared | moved)
+            }
+            _ => *self,
+        }
+    }
+
+    /// Returns an iterator over the bit patterns of each row of the mask.
+    ///
+    /// Iterates from row `0` to row `7`. Within each value, bit `0` is column `0`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use grid_mask::{GridMask, GridPoint};
+    /// let mask = GridMask::from(GridPoint::const_new::<2, 1>());
+    /// let rows: Vec<u8> = mask.rows_iter().collect();
+    ///
+    /// assert_eq!(rows, [0, 0b100, 0, 0, 0, 0, 0, 0]);
+    /// ```
+    #[must_use]
+    pub fn rows_iter(&self) -> impl ExactSizeIterator<Item = u8> + DoubleEndedIterator + FusedIterator {
+        self.to_bytes().into_iter()
+    }
+
+    /// Returns an iterator over the bit patterns of each column of the mask.
+    ///
+    /// Iterates from column `0` to column `7`. Within each value, bit `0` is row `0`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use grid_mask::{GridMask, GridPoint};
+    /// let mask = GridMask::from(GridPoint::const_new::<2, 1>());
+    /// let cols: Vec<u8> = mask.cols_iter().collect();
+    ///
+    /// assert_eq!(cols, [0, 0, 0b10, 0, 0, 0, 0, 0]);
+    /// ```
+    #[must_use]
+    pub fn cols_iter(&self) -> impl ExactSizeIterator<Item = u8> + DoubleEndedIterator + FusedIterator {
+        self.transpose().to_bytes().into_iter()
+    }
+
+    /// Returns the number of set bits in each of `lines`.
+    #[expect(clippy::cast_possible_truncation, reason = "a u8 has at most 8 set bits")]
+    const fn count_ones_each(mut lines: [u8; 8]) -> [u8; 8] {
+        let mut i = 0;
+        while i < lines.len() {
+            lines[i] = lines[i].count_ones() as u8;
+            i += 1;
+        }
+        lines
+    }
+
+    /// Returns the number of set cells in each row, from row `0` to row `7`.
+    ///
+    /// Equivalent to calling [`count_row`](Self::count_row) for each row.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use grid_mask::GridMask;
+    /// assert_eq!(GridMask::FULL.row_popcount_array(), [8; 8]);
+    /// assert_eq!(GridMask::from_bytes([0b111, 0, 0, 0, 0, 0, 0, 1]).row_popcount_array(), [3, 0, 0, 0, 0, 0, 0, 1]);
+    /// ```
+    #[must_use]
+    pub const fn row_popcount_array(&self) -> [u8; 8] {
+        Self::count_ones_each(self.to_bytes())
+    }
+
+    /// Returns the number of set cells in each column, from column `0` to column `7`.
+    ///
+    /// Equivalent to calling [`count_col`](Self::count_col) for each column.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use grid_mask::GridMask;
+    /// assert_eq!(GridMask::FULL.col_popcount_array(), [8; 8]);
+    /// assert_eq!(GridMask::from_bytes([0b111, 0, 0, 0, 0, 0, 0, 1]).col_popcount_array(), [2, 1, 1, 0, 0, 0, 0, 0]);
+    /// ```
+    #[must_use]
+    pub const fn col_popcount_array(&self) -> [u8; 8] {
+        Self::count_ones_each(self.transpose().to_bytes())
+    }
+
+    /// Returns the number of runs of set bits in `line`.
+    #[expect(clippy::cast_possible_truncation, reason = "a u8 has at most 8 set bits")]
+    const fn count_runs(line: u8) -> u8 {
+        // each run ends with a set bit whose next bit is unset
+        (line & !(line >> 1)).count_ones() as u8
+    }
+
+    /// Returns the number of unset to set transitions in `row`, reading from column `0`
+    /// to column `7`.
+    ///
+    /// Cells outside the grid count as unset, so this is the number of runs of set cells
+    /// in the row. Returns `0` if `row` is out of range.
+    ///
+    /// # Arguments
+    ///
+    /// * `row` - The row to scan.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use grid_mask::GridMask;
+    /// assert_eq!(GridMask::FULL.count_transitions_in_row(0), 1);
+    /// assert_eq!(GridMask(0b1010_1010).count_transitions_in_row(0), 4);
+    /// assert_eq!(GridMask(0b0110_0011).count_transitions_in_row(0), 2);
+    /// ```
+    #[must_use]
+    pub const fn count_transitions_in_row(&self, row: u8) -> u8 {
+        match row {
+            0..8 => Self::count_runs(self.to_bytes()[row as usize]),
+            _ => 0,
+        }
+    }
+
+    /// Returns the number of unset to set transitions in `col`, reading from row `0`
+    /// to row `7`.
+    ///
+    /// Cells outside the grid count as unset, so this is the number of runs of set cells
+    /// in the column. Returns `0` if `col` is out of range.
+    ///
+    /// # Arguments
+    ///
+    /// * `col` - The column to scan.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use grid_mask::GridMask;
+    /// assert_eq!(GridMask::FULL.count_transitions_in_col(0), 1);
+    /// assert_eq!(GridMask::from_bytes([1, 0, 1, 0, 1, 0, 1, 0]).count_transitions_in_col(0), 4);
+    /// ```
+    #[must_use]
+    pub const fn count_transitions_in_col(&self, col: u8) -> u8 {
+        match col {
+            0..8 => Self::count_runs(self.transpose().to_bytes()[col as usize]),
+            _ => 0,
+        }
     }
 
     /// Returns a range of the rows that are occupied in the mask.
@@ -7,5 +7,5 @@ mod mask;
 pub use adjacency::{Adjacency, Cardinal, Octile};
 pub use comp::*;
 
-pub use iter::{Cells, Points, Spaces};
+pub use iter::{Cells, ComponentIter, LinePoints, Points, RectPoints, Spaces};
 pub use mask::GridMask;
@@ -1,8 +1,9 @@
+use std::ops::{BitAndAssign, BitOrAssign, BitXorAssign};
 use std::str::FromStr;
 
 use grid_mask::err::OutOfBounds;
 use grid_mask::num::{Point, Rect, Size};
-use grid_mask::{ArrayIndex, ArrayPoint, ArrayVector};
+use grid_mask::{ArrayIndex, ArrayPoint, ArrayRect, ArrayVector, Cardinal, GridMask, GridPoint, Octile};
 
 use crate::macros::{test_ctor, test_mutation, test_self_method, test_try_mutation};
 
@@ -20,6 +21,8 @@ const GRID8_1_1: Grid8 = {
 type Grid10 = grid_mask::array_grid!(10, 10);
 type Point10 = ArrayPoint<10, 10>;
 
+type Grid7x3 = grid_mask::array_grid!(7, 3);
+
 mod consts {
     use super::*;
 
@@ -38,6 +41,97 @@ mod from {
     use super::*;
 
     test_ctor!(from_array: Grid10::from([u64::MAX, u64::MAX]) => Grid10::FULL);
+
+    test_ctor!(from_mask_empty: Grid8::from(GridMask::EMPTY) => Grid8::EMPTY);
+    test_ctor!(from_mask_full: Grid8::from(GridMask::FULL) => Grid8::FULL);
+    test_ctor!(from_mask_point: Grid8::from(GridMask::from(GridPoint::const_new::<1, 1>())) => GRID8_1_1);
+    test_ctor!(into_mask_empty: GridMask::from(Grid8::EMPTY) => GridMask::EMPTY);
+    test_ctor!(into_mask_full: GridMask::from(Grid8::FULL) => GridMask::FULL);
+    test_ctor!(into_mask_point: GridMask::from(GRID8_1_1) => GridMask::from(GridPoint::const_new::<1, 1>()));
+    test_ctor!(mask_round_trip: GridMask::from(Grid8::from(GridMask(0x8142_2418_1824_4281))) => GridMask(0x8142_2418_1824_4281));
+}
+
+mod from_fn {
+    use super::*;
+
+    type Grid4 = grid_mask::array_grid!(4, 4);
+
+    test_ctor!(empty: Grid10::from_fn(|_, _| false) => Grid10::EMPTY);
+    test_ctor!(full: Grid10::from_fn(|_, _| true) => Grid10::FULL);
+    test_ctor!(diagonal_4: Grid4::from_fn(|x, y| x == y) => Grid4::from_str("
+        # . . .
+        . # . .
+        . . # .
+        . . . #
+    ")?);
+    test_ctor!(single: Grid8::from_fn(|x, y| (x, y) == (1, 1)) => GRID8_1_1);
+    test_ctor!(border_10: Grid10::from_fn(|x, y| x == 0 || y == 0 || x == 9 || y == 9) => Grid10::from_str("
+        # # # # # # # # # #
+        # . . . . . . . . #
+        # . . . . . . . . #
+        # . . . . . . . . #
+        # . . . . . . . . #
+        # . . . . . . . . #
+        # . . . . . . . . #
+        # . . . . . . . . #
+        # . . . . . . . . #
+        # # # # # # # # # #
+    ")?);
+
+    #[test]
+    fn matches_points() {
+        let grid = Grid10::from_fn(|x, y| (x * 3 + y) % 7 == 0);
+        assert!(grid.cells().zip(Grid10::FULL.points()).all(|(cell, p)| cell == ((p.x() * 3 + p.y()) % 7 == 0)));
+    }
+}
+
+mod zip {
+    use super::*;
+
+    test_self_method!(and: Grid10::from_fn(|x, _| x < 5) => zip(&Grid10::from_fn(|_, y| y < 5), |a, b| a && b) => Grid10::from_fn(|x, y| x < 5 && y < 5));
+    test_self_method!(or: Grid10::from_fn(|x, _| x < 5) => zip(&Grid10::from_fn(|_, y| y < 5), |a, b| a || b) => Grid10::from_fn(|x, y| x < 5 || y < 5));
+    test_self_method!(difference: Grid10::from_fn(|x, y| x <= y) => zip(&Grid10::from_fn(|x, y| x == y), |a, b| a && !b) => Grid10::from_fn(|x, y| x < y));
+    test_self_method!(nor: Grid10::from_fn(|x, _| x < 5) => zip(&Grid10::from_fn(|_, y| y < 5), |a, b| !(a || b)) => Grid10::from_fn(|x, y| x >= 5 && y >= 5));
+    test_self_method!(always_true: Grid10::EMPTY => zip(&Grid10::EMPTY, |_, _| true) => Grid10::FULL);
+    test_self_method!(always_false: Grid10::FULL => zip(&Grid10::FULL, |_, _| false) => Grid10::EMPTY);
+
+    #[test]
+    fn matches_ops() {
+        let a = Grid10::from_fn(|x, y| (x + y) % 3 == 0);
+        let b = Grid10::from_fn(|x, y| (x * y) % 2 == 0);
+
+        assert_eq!(a.zip(&b, |a, b| a & b), a.clone() & &b);
+        assert_eq!(a.zip(&b, |a, b| a | b), a.clone() | &b);
+        assert_eq!(a.zip(&b, |a, b| a ^ b), a.clone() ^ &b);
+    }
+}
+
+mod hash {
+    use std::collections::HashSet;
+    use std::hash::BuildHasher;
+
+    use super::*;
+
+    #[test]
+    fn equal_grids_equal_hashes() {
+        let state = std::hash::RandomState::new();
+        let grid = Grid10::from_fn(|x, y| x == y);
+
+        assert_eq!(state.hash_one(&grid), state.hash_one(grid.clone()));
+        // trailing bits are cleared, so these are the same grid
+        assert_eq!(state.hash_one(Grid10::from([u64::MAX, u64::MAX])), state.hash_one(Grid10::FULL));
+        assert_eq!(state.hash_one(!Grid10::EMPTY), state.hash_one(Grid10::FULL));
+    }
+
+    #[test]
+    fn hash_set_key() {
+        let grids = [Grid10::EMPTY, Grid10::FULL, Grid10::from_fn(|x, y| x == y), Grid10::from_fn(|x, y| x == y)];
+        let set: HashSet<_> = grids.into_iter().collect();
+
+        assert_eq!(set.len(), 3);
+        assert!(set.contains(&Grid10::from_fn(|x, y| x == y)));
+        assert!(!set.contains(&Grid10::from_fn(|x, y| x != y)));
+    }
 }
 
 mod properties {
@@ -48,6 +142,13 @@ mod properties {
 
     test_self_method!(full_10_count: Grid10::FULL => count() => 100);
 
+    test_self_method!(empty_count_zeros: Grid8::EMPTY => count_zeros() => 64);
+    test_self_method!(full_count_zeros: Grid8::FULL => count_zeros() => 0);
+    test_self_method!(single_count_zeros: GRID8_1_1 => count_zeros() => 63);
+    test_self_method!(empty_10_count_zeros: Grid10::EMPTY => count_zeros() => 100);
+    test_self_method!(full_10_count_zeros: Grid10::FULL => count_zeros() => 0);
+    test_self_method!(diagonal_10_count_zeros: Grid10::from_fn(|x, y| x == y) => count_zeros() => 90);
+
     // Grid10 FULL data check:
     // Word 0: u64::MAX (64 bits)
     // Word 1: 36 bits set (100 - 64). (1 << 36) - 1.
@@ -55,6 +156,175 @@ mod properties {
     test_self_method!(full_10_data: Grid10::FULL => data() => &EXPECTED_FULL_10);
 }
 
+mod compare {
+    use super::*;
+
+    const DIAGONAL: &str = "
+        # . . . . . . . . .
+        . # . . . . . . . .
+        . . # . . . . . . .
+        . . . # . . . . . .
+        . . . . # . . . . .
+        . . . . . # . . . .
+        . . . . . . # . . .
+        . . . . . . . # . .
+        . . . . . . . . # .
+        . . . . . . . . . #
+    ";
+
+    test_self_method!(subset_empty_empty: Grid10::EMPTY => is_subset_of(&Grid10::EMPTY) => true);
+    test_self_method!(subset_empty_full: Grid10::EMPTY => is_subset_of(&Grid10::FULL) => true);
+    test_self_method!(subset_full_empty: Grid10::FULL => is_subset_of(&Grid10::EMPTY) => false);
+    test_self_method!(subset_diagonal_full: Grid10::from_str(DIAGONAL)? => is_subset_of(&Grid10::FULL) => true);
+    test_self_method!(subset_diagonal_self: Grid10::from_str(DIAGONAL)? => is_subset_of(&Grid10::from_str(DIAGONAL)?) => true);
+    test_self_method!(subset_diagonal_lower: Grid10::from_str(DIAGONAL)? => is_subset_of(&Grid10::from_fn(|x, y| x <= y)) => true);
+    test_self_method!(subset_diagonal_strict_lower: Grid10::from_str(DIAGONAL)? => is_subset_of(&Grid10::from_fn(|x, y| x < y)) => false);
+
+    test_self_method!(overlaps_empty_full: Grid10::EMPTY => overlaps(&Grid10::FULL) => false);
+    test_self_method!(overlaps_full_full: Grid10::FULL => overlaps(&Grid10::FULL) => true);
+    test_self_method!(overlaps_diagonal_lower: Grid10::from_str(DIAGONAL)? => overlaps(&Grid10::from_fn(|x, y| x <= y)) => true);
+    test_self_method!(overlaps_diagonal_strict_lower: Grid10::from_str(DIAGONAL)? => overlaps(&Grid10::from_fn(|x, y| x < y)) => false);
+    test_self_method!(overlaps_last_cell: Grid10::from_str(DIAGONAL)? => overlaps(&Grid10::from_fn(|x, y| (x, y) == (9, 9))) => true);
+
+    test_self_method!(hamming_empty_full: Grid10::EMPTY => hamming_distance(&Grid10::FULL) => 100);
+    test_self_method!(hamming_full_full: Grid10::FULL => hamming_distance(&Grid10::FULL) => 0);
+    test_self_method!(hamming_diagonal_empty: Grid10::from_str(DIAGONAL)? => hamming_distance(&Grid10::EMPTY) => 10);
+    test_self_method!(hamming_diagonal_lower: Grid10::from_str(DIAGONAL)? => hamming_distance(&Grid10::from_fn(|x, y| x <= y)) => 45);
+    test_self_method!(hamming_diagonal_upper: Grid10::from_str(DIAGONAL)? => hamming_distance(&Grid10::from_fn(|x, y| x > y)) => 55);
+}
+
+mod count_line {
+    use super::*;
+
+    const STAIRS: &str = "
+        # . . . . . .
+        # # . . . . .
+        # # # . . . #
+    ";
+
+    test_self_method!(row_full: Grid10::FULL => count_row(0) => 10);
+    test_self_method!(row_last_full: Grid10::FULL => count_row(9) => 10);
+    test_self_method!(row_empty: Grid10::EMPTY => count_row(4) => 0);
+    test_self_method!(row_0: Grid7x3::from_str(STAIRS)? => count_row(0) => 1);
+    test_self_method!(row_2: Grid7x3::from_str(STAIRS)? => count_row(2) => 4);
+    test_self_method!(row_oob: Grid7x3::FULL => count_row(3) => 0);
+    test_self_method!(row_oob_max: Grid7x3::FULL => count_row(u16::MAX) => 0);
+
+    test_self_method!(col_full: Grid10::FULL => count_col(0) => 10);
+    test_self_method!(col_last_full: Grid7x3::FULL => count_col(6) => 3);
+    test_self_method!(col_empty: Grid10::EMPTY => count_col(4) => 0);
+    test_self_method!(col_0: Grid7x3::from_str(STAIRS)? => count_col(0) => 3);
+    test_self_method!(col_2: Grid7x3::from_str(STAIRS)? => count_col(2) => 1);
+    test_self_method!(col_6: Grid7x3::from_str(STAIRS)? => count_col(6) => 1);
+    test_self_method!(col_oob: Grid7x3::FULL => count_col(7) => 0);
+    test_self_method!(col_oob_max: Grid7x3::FULL => count_col(u16::MAX) => 0);
+
+    #[test]
+    fn sums_match_count() {
+        let grid = Grid10::from_fn(|x, y| (x * y + x) % 4 == 1);
+        assert_eq!((0..10).map(|y| grid.count_row(y)).sum::<u32>(), grid.count());
+        assert_eq!((0..10).map(|x| grid.count_col(x)).sum::<u32>(), grid.count());
+    }
+}
+
+mod row_iter {
+    use super::*;
+
+    test_ctor!(count_10: Grid10::EMPTY.row_iter().count() => 10);
+    test_ctor!(count_7x3: Grid7x3::EMPTY.row_iter().count() => 3);
+    test_ctor!(len_7x3: Grid7x3::EMPTY.row_iter().map(|row| row.len()).collect::<Vec<_>>() => [7, 7, 7]);
+    test_ctor!(full_rows: Grid10::FULL.row_iter().all(|row| row.all()) => true);
+    test_ctor!(row_counts: Grid7x3::from_str("
+        # . . . . . .
+        # # . . . . .
+        # # # . . . #
+    ")?.row_iter().map(|row| row.count_ones()).collect::<Vec<_>>() => [1, 2, 4]);
+    test_ctor!(complete_rows: Grid10::from_fn(|x, y| y == 3 || y == 9 || x == 5).row_iter().enumerate().filter(|(_, row)| row.all()).map(|(y, _)| y).collect::<Vec<_>>() => [3, 9]);
+
+    #[test]
+    fn mutate_rows() {
+        let mut grid = Grid7x3::EMPTY;
+        grid.row_iter_mut().enumerate().for_each(|(y, row)| row[..=y].fill(true));
+
+        assert_eq!(grid, Grid7x3::from_fn(|x, y| x <= y));
+    }
+
+    #[test]
+    fn mutate_rows_keeps_trailing_bits_clear() {
+        let mut grid = Grid10::EMPTY;
+        grid.row_iter_mut().for_each(|row| row.fill(true));
+
+        assert_eq!(grid, Grid10::FULL);
+        assert_eq!(grid.data()[1] & Grid10::UNUSED_TRAILING_BITS, 0);
+    }
+}
+
+mod bounds {
+    use super::*;
+
+    type Rect10 = ArrayRect<10, 10>;
+
+    test_self_method!(empty: Grid10::EMPTY => bounds() => None);
+    test_self_method!(full: Grid10::FULL => bounds() => Some(Rect10::const_new::<0, 0, 10, 10>()));
+    test_self_method!(full_7x3: Grid7x3::FULL => bounds() => Some(ArrayRect::const_new::<0, 0, 7, 3>()));
+    test_self_method!(single: Grid10::from_iter([Point10::new(3, 8)?]) => bounds() => Some(Rect10::new((3, 8), (1, 1))?));
+    test_self_method!(origin: Grid10::from_iter([Point10::ORIGIN]) => bounds() => Some(Rect10::const_new::<0, 0, 1, 1>()));
+    test_self_method!(max: Grid10::from_iter([Point10::MAX]) => bounds() => Some(Rect10::const_new::<9, 9, 1, 1>()));
+    test_self_method!(corners: Grid10::from_iter([Point10::ORIGIN, Point10::MAX]) => bounds() => Some(Rect10::const_new::<0, 0, 10, 10>()));
+
+    // The leftmost and rightmost cells are in different rows than the top and bottom ones.
+    test_self_method!(diamond: Grid10::from_str("
+        . . . . . . . . . .
+        . . . . . . . . . .
+        . . . . # . . . . .
+        . . . # . # . . . .
+        . . # . . . # . . .
+        . . . # . # . . . .
+        . . . . # . . . . .
+        . . . . . . . . . .
+        . . . . . . . . . .
+        . . . . . . . . . .
+    ")? => bounds() => Some(Rect10::const_new::<2, 2, 5, 5>()));
+
+    // Cells at the end of one row and the start of the next span the full width.
+    test_self_method!(row_boundary: Grid10::from_iter([Point10::new(9, 4)?, Point10::new(0, 5)?]) => bounds() => Some(Rect10::const_new::<0, 4, 10, 2>()));
+
+    #[test]
+    fn matches_grid_mask() -> Result<(), Box<dyn std::error::Error>> {
+        let mask = grid_mask::GridMask(0x0000_2418_0018_2400);
+        let grid = Grid8::from([mask.0]);
+        let rect = mask.bounds().expect("mask should not be empty");
+
+        let expected = ArrayRect::new((rect.x().get(), rect.y().get()), (rect.w().get(), rect.h().get()))?;
+        assert_eq!(grid.bounds(), Some(expected));
+        Ok(())
+    }
+}
+
+mod visualize {
+    use super::*;
+
+    test_ctor!(empty_7x3: Grid7x3::EMPTY.visualize('#', '.').to_string() => ".......\n.......\n.......\n");
+    test_ctor!(stairs_7x3: Grid7x3::from_fn(|x, y| x <= y).visualize('X', '_').to_string() => "X______\nXX_____\nXXX____\n");
+
+    #[test]
+    fn full_10() {
+        let text = Grid10::FULL.visualize('#', '.').to_string();
+
+        assert_eq!(text.lines().count(), 10);
+        assert!(text.lines().all(|line| line == "##########"));
+    }
+
+    #[test]
+    fn round_trip() -> Result<(), Box<dyn std::error::Error>> {
+        let grid = Grid10::from_fn(|x, y| (x * 7 + y * 3) % 5 == 0);
+        let text = grid.visualize('#', '.').to_string();
+
+        assert_eq!(Grid10::from_str(&text)?, grid);
+        Ok(())
+    }
+}
+
 mod mutation {
     use super::*;
 
@@ -67,12 +337,43 @@ mod mutation {
 
     test_mutation!(fill_true_10: Grid10::EMPTY => fill(true) => Grid10::FULL);
 
+    test_mutation!(fill_from_fn_empty: Grid10::FULL => fill_from_fn(|_, _| false) => Grid10::EMPTY);
+    test_mutation!(fill_from_fn_full: Grid10::EMPTY => fill_from_fn(|_, _| true) => Grid10::FULL);
+    test_mutation!(fill_from_fn_single: Grid8::FULL => fill_from_fn(|x, y| (x, y) == (1, 1)) => GRID8_1_1);
+    test_mutation!(fill_from_fn_diagonal: Grid10::from_fn(|x, y| x < y) => fill_from_fn(|x, y| x == y) => Grid10::from_fn(|x, y| x == y));
+
     test_mutation!(
         mutate_data: Grid10::EMPTY
         => mutate_data(|data| data.fill(u64::MAX))
         => Grid10::FULL
     );
 
+    test_mutation!(toggle_set: Grid8::EMPTY => toggle(Point8::new(1, 1)?) => GRID8_1_1);
+    test_mutation!(toggle_unset: GRID8_1_1 => toggle(Point8::new(1, 1)?) => Grid8::EMPTY);
+    test_mutation!(toggle_max_10: Grid10::FULL => toggle(Point10::MAX) => Grid10::from_fn(|x, y| (x, y) != (9, 9)));
+    test_mutation!(toggle_index_set: Grid8::EMPTY => toggle_index(Index8::const_new::<9>()) => GRID8_1_1);
+    test_mutation!(toggle_index_unset: GRID8_1_1 => toggle_index(Index8::const_new::<9>()) => Grid8::EMPTY);
+    test_mutation!(toggle_index_10: Grid10::EMPTY => toggle_index(ArrayIndex::const_new::<64>()) => Grid10::from_iter([Point10::new(4, 6)?]));
+
+    #[test]
+    fn toggle_const() {
+        const TOGGLED: Grid10 = {
+            let mut grid = Grid10::EMPTY;
+            grid.toggle(Point10::ORIGIN);
+            grid.toggle(Point10::MAX);
+            grid.toggle(Point10::ORIGIN);
+            grid
+        };
+        assert_eq!(TOGGLED, Grid10::from_iter([Point10::MAX]));
+    }
+
+    test_mutation!(set_region_full: Grid10::EMPTY => set_region(ArrayRect::const_new::<0, 0, 10, 10>(), true) => Grid10::FULL);
+    test_mutation!(set_region_clear: Grid10::FULL => set_region(ArrayRect::const_new::<0, 0, 10, 10>(), false) => Grid10::EMPTY);
+    test_mutation!(set_region_cell: Grid8::EMPTY => set_region(ArrayRect::const_new::<1, 1, 1, 1>(), true) => GRID8_1_1);
+    test_mutation!(set_region_inner: Grid10::EMPTY => set_region(ArrayRect::const_new::<1, 1, 8, 8>(), true) => Grid10::FULL.erode::<Cardinal>());
+    test_mutation!(set_region_edge: Grid10::EMPTY => set_region(ArrayRect::const_new::<7, 2, 3, 2>(), true) => Grid10::from_fn(|x, y| x >= 7 && (2..4).contains(&y)));
+    test_mutation!(set_region_unset: Grid10::FULL => set_region(ArrayRect::const_new::<0, 9, 10, 1>(), false) => Grid10::from_fn(|_, y| y < 9));
+
     test_mutation!(negate_empty: Grid8::EMPTY => negate() => Grid8::FULL);
     test_mutation!(negate_full: Grid8::FULL => negate() => Grid8::EMPTY);
     test_mutation!(negate_10: Grid10::EMPTY => negate() => Grid10::FULL);
@@ -315,6 +616,89 @@ mod translation {
     ];
 }
 
+mod translate_wrapping {
+    use super::*;
+
+    const PATTERN: &str = "
+        # # . . . . .
+        . . . . . . #
+        . . . # . . .
+    ";
+
+    test_mutation!(zero: Grid7x3::from_str(PATTERN)? => translate_wrapping(ArrayVector::ZERO) => Grid7x3::from_str(PATTERN)?);
+    test_mutation!(full: Grid10::FULL => translate_wrapping(ArrayVector::new(3, -7)) => Grid10::FULL);
+    test_mutation!(empty: Grid10::EMPTY => translate_wrapping(ArrayVector::new(3, -7)) => Grid10::EMPTY);
+    test_mutation!(east: Grid7x3::from_str(PATTERN)? => translate_wrapping(ArrayVector::EAST) => Grid7x3::from_str("
+        . # # . . . .
+        # . . . . . .
+        . . . . # . .
+    ")?);
+    test_mutation!(west: Grid7x3::from_str(PATTERN)? => translate_wrapping(ArrayVector::WEST) => Grid7x3::from_str("
+        # . . . . . #
+        . . . . . # .
+        . . # . . . .
+    ")?);
+    test_mutation!(south: Grid7x3::from_str(PATTERN)? => translate_wrapping(ArrayVector::SOUTH) => Grid7x3::from_str("
+        . . . # . . .
+        # # . . . . .
+        . . . . . . #
+    ")?);
+    test_mutation!(north: Grid7x3::from_str(PATTERN)? => translate_wrapping(ArrayVector::NORTH) => Grid7x3::from_str("
+        . . . . . . #
+        . . . # . . .
+        # # . . . . .
+    ")?);
+    test_mutation!(full_period: Grid7x3::from_str(PATTERN)? => translate_wrapping(ArrayVector::new(7, 3)) => Grid7x3::from_str(PATTERN)?);
+    test_mutation!(multiple_periods: Grid7x3::from_str(PATTERN)? => translate_wrapping(ArrayVector::new(-15, 7)) => Grid7x3::from_str("
+        . . # . . . .
+        # . . . . . #
+        . . . . . # .
+    ")?);
+    test_mutation!(corner_wraps: Grid10::from_iter([Point10::MAX]) => translate_wrapping(ArrayVector::new(1, 1)) => Grid10::from_iter([Point10::ORIGIN]));
+
+    #[test]
+    fn preserves_count() {
+        let mut grid = Grid10::from_fn(|x, y| (x * 3 + y) % 7 == 0);
+        let count = grid.count();
+
+        grid.translate_wrapping(ArrayVector::new(13, -21));
+        assert_eq!(grid.count(), count);
+    }
+
+    #[test]
+    fn inverse() {
+        let grid = Grid10::from_fn(|x, y| (x * 3 + y) % 7 == 0);
+        let mut wrapped = grid.clone();
+
+        wrapped.translate_wrapping(ArrayVector::new(4, 6));
+        wrapped.translate_wrapping(ArrayVector::new(-4, -6));
+        assert_eq!(wrapped, grid);
+    }
+}
+
+mod translated {
+    use super::*;
+
+    test_self_method!(zero: GRID8_1_1 => translated(ArrayVector::ZERO) => GRID8_1_1);
+    test_self_method!(east: GRID8_1_1 => translated(ArrayVector::EAST) => Grid8::from_iter([Point8::new(2, 1)?]));
+    test_self_method!(oob: Grid10::FULL => translated(ArrayVector::new(0, -10)) => Grid10::EMPTY);
+    test_self_method!(full_east: Grid10::FULL => translated(ArrayVector::EAST) => Grid10::from_fn(|x, _| x > 0));
+
+    #[test]
+    fn matches_translate() {
+        let vec = ArrayVector::new(-3, 2);
+        let original = Grid10::from_fn(|x, y| (x + 2 * y) % 3 == 0);
+
+        let mut expected = original.clone();
+        expected.translate(vec);
+
+        assert_eq!(original.translated(vec), expected);
+        assert_eq!(original, Grid10::from_fn(|x, y| (x + 2 * y) % 3 == 0));
+    }
+
+    test_ctor!(chained: Grid10::FULL.translated(ArrayVector::EAST).translated(ArrayVector::SOUTH) => Grid10::from_fn(|x, y| x > 0 && y > 0));
+}
+
 mod bitwise {
     use super::*;
 
@@ -459,6 +843,794 @@ mod bitwise {
     }
 }
 
+mod grow {
+    use super::*;
+
+    const GRID10_4_4: Grid10 = {
+        let mut g = Grid10::EMPTY;
+        g.const_set(ArrayIndex::const_new::<44>(), true); // (4, 4) -> 44
+        g
+    };
+
+    test_self_method!(empty_cardinal: Grid10::EMPTY => grow::<Cardinal>() => Grid10::EMPTY);
+    test_self_method!(empty_octile: Grid10::EMPTY => grow::<Octile>() => Grid10::EMPTY);
+    test_self_method!(full_cardinal: Grid10::FULL => grow::<Cardinal>() => Grid10::FULL);
+    test_self_method!(full_octile: Grid10::FULL => grow::<Octile>() => Grid10::FULL);
+    test_self_method!(full_7x3: Grid7x3::FULL => grow::<Octile>() => Grid7x3::FULL);
+
+    test_self_method!(center_cardinal: GRID10_4_4 => grow::<Cardinal>() => Grid10::from_str("
+        . . . . . . . . . .
+        . . . . . . . . . .
+        . . . . . . . . . .
+        . . . . # . . . . .
+        . . . # # # . . . .
+        . . . . # . . . . .
+        . . . . . . . . . .
+        . . . . . . . . . .
+        . . . . . . . . . .
+        . . . . . . . . . .
+    ")?);
+    test_self_method!(center_octile: GRID10_4_4 => grow::<Octile>() => Grid10::from_str("
+        . . . . . . . . . .
+        . . . . . . . . . .
+        . . . . . . . . . .
+        . . . # # # . . . .
+        . . . # # # . . . .
+        . . . # # # . . . .
+        . . . . . . . . . .
+        . . . . . . . . . .
+        . . . . . . . . . .
+        . . . . . . . . . .
+    ")?);
+
+    // Cells on the right edge must not wrap to the start of the next row, and vice versa.
+    test_self_method!(no_wrap_cardinal: Grid10::from_str("
+        . . . . . . . . . #
+        # . . . . . . . . .
+        . . . . . . . . . .
+        . . . . . . . . . .
+        . . . . . . . . . .
+        . . . . . . . . . .
+        . . . . . . . . . .
+        . . . . . . . . . .
+        . . . . . . . . . .
+        . . . . . . . . . .
+    ")? => grow::<Cardinal>() => Grid10::from_str("
+        # . . . . . . . # #
+        # # . . . . . . . #
+        # . . . . . . . . .
+        . . . . . . . . . .
+        . . . . . . . . . .
+        . . . . . . . . . .
+        . . . . . . . . . .
+        . . . . . . . . . .
+        . . . . . . . . . .
+        . . . . . . . . . .
+    ")?);
+    test_self_method!(no_wrap_octile: Grid10::from_str("
+        . . . . . . . . . .
+        . . . . . . . . . .
+        . . . . . . . . . .
+        . . . . . . . . . .
+        . . . . . . . . . .
+        . . . . . . . . . .
+        . . . . . . . . . .
+        . . . . . . . . . .
+        # . . . . . . . . .
+        . . . . . . . . . #
+    ")? => grow::<Octile>() => Grid10::from_str("
+        . . . . . . . . . .
+        . . . . . . . . . .
+        . . . . . . . . . .
+        . . . . . . . . . .
+        . . . . . . . . . .
+        . . . . . . . . . .
+        . . . . . . . . . .
+        # # . . . . . . . .
+        # # . . . . . . # #
+        # # . . . . . . # #
+    ")?);
+
+    test_self_method!(non_square_corners: Grid7x3::from_str("
+        # . . . . . .
+        . . . . . . .
+        . . . . . . #
+    ")? => grow::<Octile>() => Grid7x3::from_str("
+        # # . . . . .
+        # # . . . # #
+        . . . . . # #
+    ")?);
+    test_self_method!(non_square_row: Grid7x3::from_str("
+        . . . . . . .
+        # # # # # # #
+        . . . . . . .
+    ")? => grow::<Cardinal>() => Grid7x3::FULL);
+
+    #[test]
+    fn matches_grid_mask() {
+        let mask = grid_mask::GridMask(0x0081_0000_2400_1881);
+        let grid = Grid8::from([mask.0]);
+
+        assert_eq!(grid.grow::<Cardinal>(), Grid8::from([mask.grow::<Cardinal>().0]));
+        assert_eq!(grid.grow::<Octile>(), Grid8::from([mask.grow::<Octile>().0]));
+    }
+}
+
+mod erode {
+    use super::*;
+
+    test_self_method!(empty_cardinal: Grid10::EMPTY => erode::<Cardinal>() => Grid10::EMPTY);
+    test_self_method!(empty_octile: Grid10::EMPTY => erode::<Octile>() => Grid10::EMPTY);
+    test_self_method!(full_cardinal: Grid10::FULL => erode::<Cardinal>() => Grid10::from_str("
+        . . . . . . . . . .
+        . # # # # # # # # .
+        . # # # # # # # # .
+        . # # # # # # # # .
+        . # # # # # # # # .
+        . # # # # # # # # .
+        . # # # # # # # # .
+        . # # # # # # # # .
+        . # # # # # # # # .
+        . . . . . . . . . .
+    ")?);
+    test_self_method!(full_octile: Grid10::FULL => erode::<Octile>() => Grid10::FULL.erode::<Cardinal>());
+    test_self_method!(full_count: Grid10::FULL.erode::<Cardinal>() => count() => 8 * 8);
+    test_self_method!(full_7x3: Grid7x3::FULL => erode::<Cardinal>() => Grid7x3::from_str("
+        . . . . . . .
+        . # # # # # .
+        . . . . . . .
+    ")?);
+
+    // A plus shape survives only at its center under Cardinal, but not at all under Octile.
+    const PLUS: &str = "
+        . . . . . . . . . .
+        . . . . . . . . . .
+        . . . . . . . . . .
+        . . . . # . . . . .
+        . . . # # # . . . .
+        . . . . # . . . . .
+        . . . . . . . . . .
+        . . . . . . . . . .
+        . . . . . . . . . .
+        . . . . . . . . . .
+    ";
+    test_self_method!(plus_cardinal: Grid10::from_str(PLUS)? => erode::<Cardinal>() => Grid10::from_iter([Point10::new(4, 4)?]));
+    test_self_method!(plus_octile: Grid10::from_str(PLUS)? => erode::<Octile>() => Grid10::EMPTY);
+
+    // The left and right columns are set, which must not let cells across the row
+    // boundary support each other.
+    test_self_method!(no_wrap: Grid10::from_str("
+        # # . . . . . . # #
+        # # . . . . . . # #
+        # # . . . . . . # #
+        # # . . . . . . # #
+        # # . . . . . . # #
+        # # . . . . . . # #
+        # # . . . . . . # #
+        # # . . . . . . # #
+        # # . . . . . . # #
+        # # . . . . . . # #
+    ")? => erode::<Cardinal>() => Grid10::EMPTY);
+
+    #[test]
+    fn matches_grid_mask() {
+        let mask = grid_mask::GridMask(0x7E7E_7E7E_7F3C_1800);
+        let grid = Grid8::from([mask.0]);
+
+        assert_eq!(grid.erode::<Cardinal>(), Grid8::from([mask.erode::<Cardinal>().0]));
+        assert_eq!(grid.erode::<Octile>(), Grid8::from([mask.erode::<Octile>().0]));
+    }
+
+    #[test]
+    fn erode_grow_round_trip() {
+        let grid = Grid10::FULL.erode::<Octile>();
+        assert_eq!(grid.grow::<Octile>(), Grid10::FULL);
+        assert_eq!(grid.grow::<Octile>().erode::<Octile>(), grid);
+    }
+}
+
+mod connected {
+    use super::*;
+
+    const REGIONS: &str = "
+        # # # . . . . . . #
+        # . # . . . . . # .
+        # # # . . . . # . .
+        . . . . . . . . . .
+        . . . . # # # # # #
+        . . . . # . . . . .
+        . . . . # . . . . .
+        # . . . # . . . . .
+        . # . . # # # # # #
+        # . # . . . . . . #
+    ";
+
+    test_self_method!(empty: Grid10::EMPTY => connected::<Cardinal>(Point10::ORIGIN) => Grid10::EMPTY);
+    test_self_method!(full: Grid10::FULL => connected::<Octile>(Point10::MAX) => Grid10::FULL);
+    test_self_method!(unset_seed: Grid10::from_str(REGIONS)? => connected::<Cardinal>(Point10::new(1, 1)?) => Grid10::EMPTY);
+
+    test_self_method!(ring: Grid10::from_str(REGIONS)? => connected::<Cardinal>(Point10::new(2, 2)?) => Grid10::from_str("
+        # # # . . . . . . .
+        # . # . . . . . . .
+        # # # . . . . . . .
+        . . . . . . . . . .
+        . . . . . . . . . .
+        . . . . . . . . . .
+        . . . . . . . . . .
+        . . . . . . . . . .
+        . . . . . . . . . .
+        . . . . . . . . . .
+    ")?);
+
+    // The diagonal only connects under Octile, and must not wrap from (9, 0) onto row 1.
+    test_self_method!(diagonal_cardinal: Grid10::from_str(REGIONS)? => connected::<Cardinal>(Point10::new(9, 0)?) => Grid10::from_iter([Point10::new(9, 0)?]));
+    test_self_method!(diagonal_octile: Grid10::from_str(REGIONS)? => connected::<Octile>(Point10::new(9, 0)?) => Grid10::from_iter([
+        Point10::new(9, 0)?,
+        Point10::new(8, 1)?,
+        Point10::new(7, 2)?,
+    ]));
+
+    test_self_method!(snake: Grid10::from_str(REGIONS)? => connected::<Cardinal>(Point10::new(9, 4)?) => Grid10::from_str("
+        . . . . . . . . . .
+        . . . . . . . . . .
+        . . . . . . . . . .
+        . . . . . . . . . .
+        . . . . # # # # # #
+        . . . . # . . . . .
+        . . . . # . . . . .
+        . . . . # . . . . .
+        . . . . # # # # # #
+        . . . . . . . . . #
+    ")?);
+
+    test_self_method!(x_cardinal: Grid10::from_str(REGIONS)? => connected::<Cardinal>(Point10::new(1, 8)?) => Grid10::from_iter([Point10::new(1, 8)?]));
+    test_self_method!(x_octile: Grid10::from_str(REGIONS)? => connected::<Octile>(Point10::new(1, 8)?) => Grid10::from_iter([
+        Point10::new(0, 7)?,
+        Point10::new(1, 8)?,
+        Point10::new(0, 9)?,
+        Point10::new(2, 9)?,
+    ]));
+
+    #[test]
+    fn matches_grid_mask() {
+        let mask = grid_mask::GridMask(0x8142_2418_0018_2442);
+        let grid = Grid8::from([mask.0]);
+
+        grid.points().for_each(|point| {
+            let index = u8::try_from(point.x() + point.y() * 8).expect("index should fit in u8");
+            let expected = mask
+                .contiguous::<Cardinal>(grid_mask::GridPoint::from_linear_index(index).expect("index should be valid"));
+            assert_eq!(grid.connected::<Cardinal>(point), Grid8::from([expected.0]));
+        });
+    }
+}
+
+mod count_components {
+    use super::*;
+
+    test_self_method!(empty: Grid10::EMPTY => count_components::<Cardinal>() => 0);
+    test_self_method!(full_cardinal: Grid10::FULL => count_components::<Cardinal>() => 1);
+    test_self_method!(full_octile: Grid10::FULL => count_components::<Octile>() => 1);
+    test_self_method!(two_cells: Grid10::from_iter([Point10::new(1, 1)?, Point10::new(8, 8)?]) => count_components::<Cardinal>() => 2);
+
+    // Cells at the end of one row and the start of the next are not adjacent.
+    test_self_method!(no_wrap: Grid10::from_iter([Point10::new(9, 0)?, Point10::new(0, 1)?]) => count_components::<Octile>() => 2);
+
+    test_self_method!(diagonal_cardinal: Grid7x3::from_str("
+        . . . . . . #
+        . . . . . # .
+        . . . . # . .
+    ")? => count_components::<Cardinal>() => 3);
+    test_self_method!(diagonal_octile: Grid7x3::from_str("
+        . . . . . . #
+        . . . . . # .
+        . . . . # . .
+    ")? => count_components::<Octile>() => 1);
+    test_self_method!(stripes: Grid7x3::from_fn(|x, _| x % 2 == 0) => count_components::<Octile>() => 4);
+
+    #[test]
+    fn matches_grid_mask() {
+        let mask = grid_mask::GridMask(0x8142_2418_0018_2442);
+        let grid = Grid8::from([mask.0]);
+
+        assert_eq!(grid.count_components::<Cardinal>(), mask.count_components::<Cardinal>());
+        assert_eq!(grid.count_components::<Octile>(), mask.count_components::<Octile>());
+    }
+}
+
+mod components {
+    use super::*;
+
+    const ISLANDS: &str = "
+        # # . . . . #
+        . . . # . . #
+        . . # # . # .
+    ";
+
+    #[test]
+    fn empty() {
+        assert_eq!(Grid10::EMPTY.components::<Cardinal>().next(), None);
+    }
+
+    #[test]
+    fn full() {
+        assert_eq!(Grid10::FULL.components::<Octile>().collect::<Vec<_>>(), [Grid10::FULL]);
+    }
+
+    #[test]
+    fn islands_cardinal() -> Result<(), Box<dyn std::error::Error>> {
+        let components: Vec<_> = Grid7x3::from_str(ISLANDS)?.components::<Cardinal>().collect();
+
+        assert_eq!(
+            components,
+            [
+                Grid7x3::from_str(
+                    "
+                # # . . . . .
+                . . . . . . .
+                . . . . . . .
+            "
+                )?,
+                Grid7x3::from_str(
+                    "
+                . . . . . . #
+                . . . . . . #
+                . . . . . . .
+            "
+                )?,
+                Grid7x3::from_str(
+                    "
+                . . . . . . .
+                . . . # . . .
+                . . # # . . .
+            "
+                )?,
+                Grid7x3::from_str(
+                    "
+                . . . . . . .
+                . . . . . . .
+                . . . . . # .
+            "
+                )?,
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn islands_octile() -> Result<(), Box<dyn std::error::Error>> {
+        let components: Vec<_> = Grid7x3::from_str(ISLANDS)?.components::<Octile>().collect();
+
+        assert_eq!(
+            components,
+            [
+                Grid7x3::from_str(
+                    "
+                # # . . . . .
+                . . . . . . .
+                . . . . . . .
+            "
+                )?,
+                Grid7x3::from_str(
+                    "
+                . . . . . . #
+                . . . . . . #
+                . . . . . # .
+            "
+                )?,
+                Grid7x3::from_str(
+                    "
+                . . . . . . .
+                . . . # . . .
+                . . # # . . .
+            "
+                )?,
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn disjoint_and_covering() {
+        let grid = Grid10::from_fn(|x, y| (x * 7 + y * 3) % 5 < 2);
+        let components: Vec<_> = grid.components::<Cardinal>().collect();
+
+        let union = components.iter().fold(Grid10::EMPTY, |acc, component| acc | component);
+        assert_eq!(union, grid);
+
+        let total: u32 = components.iter().map(Grid10::count).sum();
+        assert_eq!(total, grid.count());
+    }
+
+    #[test]
+    fn fused() {
+        let mut components = Grid10::from_iter([Point10::ORIGIN]).components::<Cardinal>();
+
+        assert!(components.next().is_some());
+        assert_eq!(components.next(), None);
+        assert_eq!(components.next(), None);
+    }
+
+    #[test]
+    fn matches_grid_mask() {
+        let mask = grid_mask::GridMask(0x8142_2418_0018_2442);
+        let grid = Grid8::from(mask);
+
+        let expected: Vec<_> = mask.components::<Octile>().map(Grid8::from).collect();
+        assert_eq!(grid.components::<Octile>().collect::<Vec<_>>(), expected);
+    }
+}
+
+mod cellular_automaton_step {
+    use super::*;
+
+    type Grid5 = grid_mask::array_grid!(5, 5);
+    type Grid16 = grid_mask::array_grid!(16, 16);
+
+    fn life(cell: bool, neighbors: u8) -> bool {
+        neighbors == 3 || (cell && neighbors == 2)
+    }
+
+    const BLINKER_H: &str = "
+        . . . . .
+        . . . . .
+        . # # # .
+        . . . . .
+        . . . . .
+    ";
+
+    const BLINKER_V: &str = "
+        . . . . .
+        . . # . .
+        . . # . .
+        . . # . .
+        . . . . .
+    ";
+
+    test_self_method!(blinker_h: Grid5::from_str(BLINKER_H)? => cellular_automaton_step::<Octile>(life) => Grid5::from_str(BLINKER_V)?);
+    test_self_method!(blinker_v: Grid5::from_str(BLINKER_V)? => cellular_automaton_step::<Octile>(life) => Grid5::from_str(BLINKER_H)?);
+    test_self_method!(empty: Grid16::EMPTY => cellular_automaton_step::<Octile>(life) => Grid16::EMPTY);
+    test_self_method!(block: Grid16::from_fn(|x, y| (7..=8).contains(&x) && (7..=8).contains(&y)) => cellular_automaton_step::<Octile>(life) => Grid16::from_fn(|x, y| (7..=8).contains(&x) && (7..=8).contains(&y)));
+    test_self_method!(identity: Grid16::from_fn(|x, y| (x + y) % 3 == 0) => cellular_automaton_step::<Cardinal>(|cell, _| cell) => Grid16::from_fn(|x, y| (x + y) % 3 == 0));
+    test_self_method!(cardinal_counts: Grid16::FULL => cellular_automaton_step::<Cardinal>(|_, n| n == 4) => Grid16::from_fn(|x, y| x > 0 && y > 0 && x < 15 && y < 15));
+    test_self_method!(octile_corners: Grid16::FULL => cellular_automaton_step::<Octile>(|_, n| n == 3) => Grid16::from_fn(|x, y| (x == 0 || x == 15) && (y == 0 || y == 15)));
+    test_self_method!(cardinal_grow: Grid16::from_fn(|x, y| (x, y) == (5, 5)) => cellular_automaton_step::<Cardinal>(|cell, n| cell || n > 0) => Grid16::from_fn(|x, y| (x, y) == (5, 5)).grow::<Cardinal>());
+
+    #[test]
+    fn blinker_16() {
+        let horizontal = Grid16::from_fn(|x, y| y == 8 && (7..=9).contains(&x));
+        let vertical = Grid16::from_fn(|x, y| x == 8 && (7..=9).contains(&y));
+
+        let next = horizontal.cellular_automaton_step::<Octile>(life);
+        assert_eq!(next, vertical);
+        assert_eq!(next.cellular_automaton_step::<Octile>(life), horizontal);
+    }
+}
+
+mod flip {
+    use super::*;
+
+    const ASYMMETRIC: &str = "
+        # # # . . . . . . .
+        # . . . . . . . . .
+        # # . . . . . . . .
+        # . . . . . . . . .
+        # . . . . . . . . .
+        . . . . . . . . . .
+        . . . . . . . . . .
+        . . . . . . . . . .
+        . . . . . . . . . .
+        . . . . . . . . . #
+    ";
+
+    test_mutation!(horizontal: Grid10::from_str(ASYMMETRIC)? => flip_horizontal() => Grid10::from_str("
+        . . . . . . . # # #
+        . . . . . . . . . #
+        . . . . . . . . # #
+        . . . . . . . . . #
+        . . . . . . . . . #
+        . . . . . . . . . .
+        . . . . . . . . . .
+        . . . . . . . . . .
+        . . . . . . . . . .
+        # . . . . . . . . .
+    ")?);
+    test_mutation!(vertical: Grid10::from_str(ASYMMETRIC)? => flip_vertical() => Grid10::from_str("
+        . . . . . . . . . #
+        . . . . . . . . . .
+        . . . . . . . . . .
+        . . . . . . . . . .
+        . . . . . . . . . .
+        # . . . . . . . . .
+        # . . . . . . . . .
+        # # . . . . . . . .
+        # . . . . . . . . .
+        # # # . . . . . . .
+    ")?);
+
+    test_mutation!(horizontal_7x3: Grid7x3::from_str("
+        # # . . . . .
+        . . . # . . .
+        . . . . . # .
+    ")? => flip_horizontal() => Grid7x3::from_str("
+        . . . . . # #
+        . . . # . . .
+        . # . . . . .
+    ")?);
+    test_mutation!(vertical_7x3: Grid7x3::from_str("
+        # # . . . . .
+        . . . # . . .
+        . . . . . # .
+    ")? => flip_vertical() => Grid7x3::from_str("
+        . . . . . # .
+        . . . # . . .
+        # # . . . . .
+    ")?);
+
+    test_mutation!(horizontal_full: Grid10::FULL => flip_horizontal() => Grid10::FULL);
+    test_mutation!(vertical_full: Grid10::FULL => flip_vertical() => Grid10::FULL);
+    test_mutation!(vertical_empty: Grid10::EMPTY => flip_vertical() => Grid10::EMPTY);
+
+    test_self_method!(flipped_horizontal: Grid10::from_str(ASYMMETRIC)? => flipped_horizontal() => {
+        let mut grid = Grid10::from_str(ASYMMETRIC)?;
+        grid.flip_horizontal();
+        grid
+    });
+    test_self_method!(flipped_vertical: Grid10::from_str(ASYMMETRIC)? => flipped_vertical() => {
+        let mut grid = Grid10::from_str(ASYMMETRIC)?;
+        grid.flip_vertical();
+        grid
+    });
+
+    test_ctor!(horizontal_involution: Grid10::from_str(ASYMMETRIC)?.flipped_horizontal().flipped_horizontal() => Grid10::from_str(ASYMMETRIC)?);
+    test_ctor!(vertical_involution: Grid10::from_str(ASYMMETRIC)?.flipped_vertical().flipped_vertical() => Grid10::from_str(ASYMMETRIC)?);
+
+    #[test]
+    fn matches_grid_mask() {
+        let mask = grid_mask::GridMask(0x8142_2418_0018_2442);
+        let grid = Grid8::from([mask.0]);
+
+        assert_eq!(grid.flipped_horizontal(), Grid8::from([mask.flip_horizontal().0]));
+        assert_eq!(grid.flipped_vertical(), Grid8::from([mask.flip_vertical().0]));
+    }
+
+    #[test]
+    fn trailing_bits_clear() {
+        let grid = Grid10::FULL.flipped_vertical().flipped_horizontal();
+        assert_eq!(grid.data()[1] & Grid10::UNUSED_TRAILING_BITS, 0);
+    }
+}
+
+mod swap_rows {
+    use super::*;
+
+    const PATTERN: &str = "
+        # # . . . . .
+        . . . . . . #
+        . . . # . . .
+    ";
+
+    test_mutation!(swap_0_2: Grid7x3::from_str(PATTERN)? => swap_rows(0, 2) => Grid7x3::from_str("
+        . . . # . . .
+        . . . . . . #
+        # # . . . . .
+    ")?);
+    test_mutation!(swap_2_1: Grid7x3::from_str(PATTERN)? => swap_rows(2, 1) => Grid7x3::from_str("
+        # # . . . . .
+        . . . # . . .
+        . . . . . . #
+    ")?);
+    test_mutation!(same_row: Grid7x3::from_str(PATTERN)? => swap_rows(1, 1) => Grid7x3::from_str(PATTERN)?);
+    test_mutation!(oob_r1: Grid7x3::from_str(PATTERN)? => swap_rows(3, 0) => Grid7x3::from_str(PATTERN)?);
+    test_mutation!(oob_r2: Grid7x3::from_str(PATTERN)? => swap_rows(0, u16::MAX) => Grid7x3::from_str(PATTERN)?);
+    test_mutation!(word_boundary: Grid10::from_fn(|_, y| y == 6) => swap_rows(6, 0) => Grid10::from_fn(|_, y| y == 0));
+    test_mutation!(last_row: Grid10::from_fn(|x, y| y == 9 && x % 2 == 0) => swap_rows(9, 3) => Grid10::from_fn(|x, y| y == 3 && x % 2 == 0));
+}
+
+mod rotate {
+    use grid_mask::ArrayGrid;
+
+    use super::*;
+
+    type Grid4x8 = ArrayGrid<4, 8, 1>;
+    type Grid8x4 = ArrayGrid<8, 4, 1>;
+    type Grid10x3 = grid_mask::array_grid!(10, 3);
+    type Grid3x10 = grid_mask::array_grid!(3, 10);
+
+    const TALL: &str = "
+        # # # #
+        # . . .
+        # . . .
+        . . . .
+        . . . .
+        . . . .
+        . . . .
+        . . . #
+    ";
+
+    test_ctor!(rotate_90_4x8: Grid4x8::from_str(TALL)?.rotate_90::<1>() => Grid8x4::from_str("
+        . . . . . # # #
+        . . . . . . . #
+        . . . . . . . #
+        # . . . . . . #
+    ")?);
+    test_ctor!(rotate_90_10x3: Grid10x3::from_str("
+        # # . . . . . . . .
+        . . . . . . . . . .
+        . . . . . . . . . #
+    ")?.rotate_90::<1>() => Grid3x10::from_str("
+        . . #
+        . . #
+        . . .
+        . . .
+        . . .
+        . . .
+        . . .
+        . . .
+        . . .
+        # . .
+    ")?);
+    test_ctor!(rotate_90_full: Grid10::FULL.rotate_90::<2>() => Grid10::FULL);
+    test_ctor!(rotate_90_empty: Grid4x8::EMPTY.rotate_90::<1>() => Grid8x4::EMPTY);
+    test_ctor!(rotate_90_four_times: Grid4x8::from_str(TALL)?.rotate_90::<1>().rotate_90::<1>().rotate_90::<1>().rotate_90::<1>() => Grid4x8::from_str(TALL)?);
+    test_ctor!(rotate_90_twice: Grid4x8::from_str(TALL)?.rotate_90::<1>().rotate_90::<1>() => Grid4x8::from_str(TALL)?.rotate_180());
+
+    test_self_method!(rotate_180_4x8: Grid4x8::from_str(TALL)? => rotate_180() => Grid4x8::from_str("
+        # . . .
+        . . . .
+        . . . .
+        . . . .
+        . . . .
+        . . . #
+        . . . #
+        # # # #
+    ")?);
+    test_self_method!(rotate_180_full: Grid10::FULL => rotate_180() => Grid10::FULL);
+    test_self_method!(rotate_180_flips: Grid10x3::from_fn(|x, y| x < 3 && y == 0) => rotate_180() => Grid10x3::from_fn(|x, y| x < 3 && y == 0).flipped_horizontal().flipped_vertical());
+
+    #[test]
+    fn matches_grid_mask() {
+        let mask = grid_mask::GridMask(0x8142_2418_0018_2447);
+        let grid = Grid8::from([mask.0]);
+
+        assert_eq!(grid.rotate_90::<1>(), Grid8::from([mask.rotate_90().0]));
+        assert_eq!(grid.rotate_180(), Grid8::from([mask.rotate_180().0]));
+    }
+
+    #[test]
+    fn trailing_bits_clear() {
+        let grid = Grid3x10::FULL.rotate_180().rotate_90::<1>();
+        assert_eq!(grid, Grid10x3::FULL);
+        assert_eq!(grid.data()[0] & Grid10x3::UNUSED_TRAILING_BITS, 0);
+    }
+}
+
+mod ops {
+    use super::*;
+
+    const LEFT: &str = "
+        # # # # # . . . . .
+        # # # # # . . . . .
+        # # # # # . . . . .
+        # # # # # . . . . .
+        # # # # # . . . . .
+        # # # # # . . . . .
+        # # # # # . . . . .
+        # # # # # . . . . .
+        # # # # # . . . . .
+        # # # # # . . . . .
+    ";
+    const TOP: &str = "
+        # # # # # # # # # #
+        # # # # # # # # # #
+        # # # # # # # # # #
+        # # # # # # # # # #
+        # # # # # # # # # #
+        . . . . . . . . . .
+        . . . . . . . . . .
+        . . . . . . . . . .
+        . . . . . . . . . .
+        . . . . . . . . . .
+    ";
+
+    test_ctor!(and_full_empty: Grid8::FULL & Grid8::EMPTY => Grid8::EMPTY);
+    test_ctor!(or_full_empty: Grid8::FULL | Grid8::EMPTY => Grid8::FULL);
+    test_ctor!(xor_full_full: Grid8::FULL ^ Grid8::FULL => Grid8::EMPTY);
+    test_ctor!(not_empty: !Grid10::EMPTY => Grid10::FULL);
+    test_ctor!(not_full: !Grid10::FULL => Grid10::EMPTY);
+
+    test_ctor!(and: Grid10::from_str(LEFT)? & Grid10::from_str(TOP)? => Grid10::from_fn(|x, y| x < 5 && y < 5));
+    test_ctor!(or: Grid10::from_str(LEFT)? | Grid10::from_str(TOP)? => Grid10::from_fn(|x, y| x < 5 || y < 5));
+    test_ctor!(xor: Grid10::from_str(LEFT)? ^ Grid10::from_str(TOP)? => Grid10::from_fn(|x, y| (x < 5) != (y < 5)));
+    test_ctor!(not: !Grid10::from_str(LEFT)? => Grid10::from_fn(|x, _| x >= 5));
+    test_ctor!(and_ref: Grid10::from_str(LEFT)? & &Grid10::FULL => Grid10::from_str(LEFT)?);
+
+    test_mutation!(and_assign: Grid10::from_str(LEFT)? => bitand_assign(Grid10::from_str(TOP)?) => Grid10::from_fn(|x, y| x < 5 && y < 5));
+    test_mutation!(or_assign: Grid10::from_str(LEFT)? => bitor_assign(Grid10::from_str(TOP)?) => Grid10::from_fn(|x, y| x < 5 || y < 5));
+    test_mutation!(xor_assign: Grid10::from_str(LEFT)? => bitxor_assign(&Grid10::from_str(TOP)?) => Grid10::from_fn(|x, y| (x < 5) != (y < 5)));
+
+    #[test]
+    fn trailing_bits_clear() {
+        let left = Grid10::from_str(LEFT).expect("pattern should parse");
+        let results = [
+            !left.clone(),
+            !Grid10::EMPTY,
+            left.clone() | Grid10::FULL,
+            left.clone() ^ Grid10::FULL,
+            !(left.clone() & Grid10::FULL),
+        ];
+
+        results.iter().for_each(|grid| assert_eq!(grid.data()[1] & Grid10::UNUSED_TRAILING_BITS, 0));
+    }
+}
+
+mod blit {
+    use super::*;
+
+    type Grid3 = grid_mask::array_grid!(3, 3);
+
+    const RING: &str = "
+        # # #
+        # . #
+        # # #
+    ";
+
+    test_try_mutation!(full_onto_empty: Grid10::EMPTY => blit(&Grid10::FULL, Point10::ORIGIN) => (Ok(()), Grid10::FULL));
+    test_try_mutation!(empty_onto_full: Grid10::FULL => blit(&Grid10::EMPTY, Point10::ORIGIN) => (Ok(()), Grid10::EMPTY));
+    test_try_mutation!(ring_onto_full: Grid10::FULL => blit(&Grid3::from_str(RING)?, Point10::new(4, 5)?) => (Ok(()), Grid10::from_fn(|x, y| (x, y) != (5, 6))));
+    test_try_mutation!(ring_onto_empty: Grid10::EMPTY => blit(&Grid3::from_str(RING)?, Point10::new(7, 7)?) => (Ok(()), Grid10::from_fn(|x, y| x >= 7 && y >= 7 && (x, y) != (8, 8))));
+    test_try_mutation!(oob_x: Grid10::FULL => blit(&Grid3::EMPTY, Point10::new(8, 0)?) => (Err(OutOfBounds), Grid10::FULL));
+    test_try_mutation!(oob_y: Grid10::FULL => blit(&Grid3::EMPTY, Point10::new(0, 8)?) => (Err(OutOfBounds), Grid10::FULL));
+    test_try_mutation!(too_big: Grid8::EMPTY => blit(&Grid10::FULL, Point8::ORIGIN) => (Err(OutOfBounds), Grid8::EMPTY));
+
+    #[test]
+    fn view_source() -> Result<(), Box<dyn std::error::Error>> {
+        let source = Grid10::from_fn(|x, y| x == y);
+        let view = source.get(ArrayRect::const_new::<2, 2, 3, 3>());
+
+        let mut grid = Grid8::FULL;
+        grid.blit(view, Point8::ORIGIN)?;
+
+        assert_eq!(grid, Grid8::from_fn(|x, y| x >= 3 || y >= 3 || x == y));
+        Ok(())
+    }
+}
+
+mod copy_region {
+    use super::*;
+
+    const PATTERN: &str = "
+        # # . . . . .
+        # . . . . . #
+        . . . # . . .
+    ";
+
+    test_try_mutation!(to_self: Grid7x3::from_str(PATTERN)? => copy_region(ArrayRect::const_new::<0, 0, 3, 3>(), ArrayPoint::ORIGIN) => (Ok(()), Grid7x3::from_str(PATTERN)?));
+    test_try_mutation!(disjoint: Grid7x3::from_str(PATTERN)? => copy_region(ArrayRect::const_new::<0, 0, 2, 2>(), ArrayPoint::new(4, 1)?) => (Ok(()), Grid7x3::from_str("
+        # # . . . . .
+        # . . . # # #
+        . . . # # . .
+    ")?));
+    test_try_mutation!(overlap_forward: Grid7x3::from_str(PATTERN)? => copy_region(ArrayRect::const_new::<0, 0, 4, 3>(), ArrayPoint::new(1, 0)?) => (Ok(()), Grid7x3::from_str("
+        # # # . . . .
+        # # . . . . #
+        . . . . # . .
+    ")?));
+    test_try_mutation!(overlap_backward: Grid7x3::from_str(PATTERN)? => copy_region(ArrayRect::const_new::<1, 0, 6, 3>(), ArrayPoint::ORIGIN) => (Ok(()), Grid7x3::from_str("
+        # . . . . . .
+        . . . . . # #
+        . . # . . . .
+    ")?));
+    test_try_mutation!(overlap_rows: Grid7x3::from_str(PATTERN)? => copy_region(ArrayRect::const_new::<0, 0, 7, 2>(), ArrayPoint::new(0, 1)?) => (Ok(()), Grid7x3::from_str("
+        # # . . . . .
+        # # . . . . .
+        # . . . . . #
+    ")?));
+    test_try_mutation!(oob: Grid7x3::from_str(PATTERN)? => copy_region(ArrayRect::const_new::<0, 0, 2, 2>(), ArrayPoint::new(6, 0)?) => (Err(OutOfBounds), Grid7x3::from_str(PATTERN)?));
+    test_try_mutation!(oob_y: Grid10::FULL => copy_region(ArrayRect::const_new::<0, 0, 1, 4>(), Point10::new(0, 7)?) => (Err(OutOfBounds), Grid10::FULL));
+    test_try_mutation!(across_words: Grid10::from_fn(|_, y| y == 0) => copy_region(ArrayRect::const_new::<0, 0, 10, 1>(), Point10::new(0, 9)?) => (Ok(()), Grid10::from_fn(|_, y| y == 0 || y == 9)));
+}
+
 mod from_str {
     use super::*;
     use grid_mask::err::PatternError;
@@ -477,6 +1649,32 @@ mod from_str {
     test_ctor!(invalid: Grid8::from_str(INVALID_CHAR_STR) => Err(PatternError::InvalidChar('?')));
 }
 
+mod from_pattern {
+    use super::*;
+    use crate::macros::test_panic;
+    use grid_mask::err::PatternError;
+
+    const STAIRS: &str = "
+        X o o o o o o
+        X X o o o o o
+        X X X o o o o
+    ";
+
+    test_ctor!(custom: Grid7x3::from_pattern(STAIRS, 'X', 'o') => Ok(Grid7x3::from_fn(|x, y| x <= y)));
+    test_ctor!(swapped: Grid7x3::from_pattern(STAIRS, 'o', 'X') => Ok(Grid7x3::from_fn(|x, y| x > y)));
+    test_ctor!(default_chars: Grid10::from_pattern(&Grid10::FULL.visualize('#', '.').to_string(), '#', '.') => Ok(Grid10::FULL));
+    test_ctor!(matches_from_str: Grid7x3::from_pattern(&STAIRS.replace('X', "#").replace('o', "."), '#', '.') => Grid7x3::from_str(&STAIRS.replace('X', "#").replace('o', ".")));
+
+    test_ctor!(invalid: Grid7x3::from_pattern(STAIRS, '#', '.') => Err(PatternError::InvalidChar('X')));
+    test_ctor!(invalid_default: Grid7x3::from_pattern(&STAIRS.replace('X', "#"), '#', 'O') => Err(PatternError::InvalidChar('o')));
+    test_ctor!(too_short: Grid7x3::from_pattern("X o X o", 'X', 'o') => Err(PatternError::TooShort(4)));
+    test_ctor!(too_long: Grid7x3::from_pattern(&STAIRS.repeat(2), 'X', 'o') => Err(PatternError::TooLong));
+
+    test_panic!(same_chars: Grid7x3::from_pattern(STAIRS, 'X', 'X') => "set and unset must be different characters");
+    test_panic!(whitespace_set: Grid7x3::from_pattern(STAIRS, ' ', 'X') => "set and unset must not be whitespace");
+    test_panic!(whitespace_unset: Grid7x3::from_pattern(STAIRS, 'X', '\n') => "set and unset must not be whitespace");
+}
+
 mod extend {
     use super::*;
 
@@ -22,6 +22,38 @@ mod properties {
     test_self_method!(local_1_1: SAMPLE_GRID.get(RECT_1_1_2_2) => get(Point::new(1, 1)) => Ok(true));
     test_self_method!(local_0_1: SAMPLE_GRID.get(RECT_1_1_2_2) => get(Point::new(0, 1)) => Ok(false));
     test_self_method!(local_oob: SAMPLE_GRID.get(RECT_1_1_2_2) => get(Point::new(2, 0)) => Err(OutOfBounds));
+
+    test_self_method!(all_partial: SAMPLE_GRID.get(RECT_1_1_2_2) => all() => false);
+    test_self_method!(all_full: SAMPLE_GRID.get(ArrayRect::const_new::<1, 1, 2, 1>()) => all() => true);
+    test_self_method!(all_empty: Grid8::EMPTY.get(RECT_1_1_2_2) => all() => false);
+
+    test_self_method!(any_partial: SAMPLE_GRID.get(RECT_1_1_2_2) => any() => true);
+    test_self_method!(any_single: SAMPLE_GRID.get(ArrayRect::const_new::<4, 4, 1, 1>()) => any() => true);
+    test_self_method!(any_empty: SAMPLE_GRID.get(ArrayRect::const_new::<5, 0, 3, 8>()) => any() => false);
+
+    test_self_method!(is_empty_partial: SAMPLE_GRID.get(RECT_1_1_2_2) => is_empty() => false);
+    test_self_method!(is_empty_unset: SAMPLE_GRID.get(ArrayRect::const_new::<0, 2, 2, 2>()) => is_empty() => true);
+    test_self_method!(is_empty_full: Grid8::FULL.get(RECT_1_1_2_2) => is_empty() => false);
+
+    test_self_method!(is_full_partial: SAMPLE_GRID.get(RECT_1_1_2_2) => is_full() => false);
+    test_self_method!(is_full_full: Grid8::FULL.get(ArrayRect::const_new::<0, 0, 8, 8>()) => is_full() => true);
+    test_self_method!(is_full_set: SAMPLE_GRID.get(ArrayRect::const_new::<2, 1, 1, 2>()) => is_full() => true);
+}
+
+mod is_subset_of {
+    use crate::macros::test_panic;
+
+    use super::*;
+
+    test_self_method!(self_subset: SAMPLE_GRID.get(RECT_1_1_2_2) => is_subset_of(SAMPLE_GRID.get(RECT_1_1_2_2)) => true);
+    test_self_method!(of_full: SAMPLE_GRID.get(RECT_1_1_2_2) => is_subset_of(Grid8::FULL.get(RECT_1_1_2_2)) => true);
+    test_self_method!(of_empty: SAMPLE_GRID.get(RECT_1_1_2_2) => is_subset_of(Grid8::EMPTY.get(RECT_1_1_2_2)) => false);
+    test_self_method!(empty_of_empty: Grid8::EMPTY.get(RECT_1_1_2_2) => is_subset_of(Grid8::EMPTY.get(RECT_1_1_2_2)) => true);
+    test_self_method!(offset_match: SAMPLE_GRID.get(ArrayRect::const_new::<4, 4, 1, 1>()) => is_subset_of(SAMPLE_GRID.get(ArrayRect::const_new::<1, 1, 1, 1>())) => true);
+    test_self_method!(offset_mismatch: SAMPLE_GRID.get(ArrayRect::const_new::<1, 1, 2, 2>()) => is_subset_of(SAMPLE_GRID.get(ArrayRect::const_new::<2, 1, 2, 2>())) => false);
+    test_self_method!(superset: Grid8::FULL.get(RECT_1_1_2_2) => is_subset_of(SAMPLE_GRID.get(RECT_1_1_2_2)) => false);
+
+    test_panic!(size_mismatch: SAMPLE_GRID.get(RECT_1_1_2_2).is_subset_of(SAMPLE_GRID.get(ArrayRect::const_new::<0, 0, 2, 3>())) => "views must have the same size");
 }
 
 mod mutation {
@@ -48,6 +80,43 @@ mod mutation {
         assert!(grid.get(Point8::const_new::<2, 2>()));
     }
 
+    #[test]
+    fn fill_from() {
+        let mut grid = Grid8::FULL;
+        grid.get_mut(RECT_1_1_2_2).fill_from(SAMPLE_GRID.get(RECT_1_1_2_2));
+
+        // Global (1, 2) is unset in the source, so it is cleared
+        assert!(!grid.get(Point8::const_new::<1, 2>()));
+        assert_eq!(grid.count(), 63);
+    }
+
+    #[test]
+    fn fill_from_restores() {
+        let mut grid = SAMPLE_GRID;
+        let saved = SAMPLE_GRID;
+
+        grid.get_mut(RECT_1_1_2_2).fill(false);
+        assert_eq!(grid.count(), 1);
+
+        grid.get_mut(RECT_1_1_2_2).fill_from(saved.get(RECT_1_1_2_2));
+        assert_eq!(grid, SAMPLE_GRID);
+    }
+
+    #[test]
+    fn fill_from_offset() {
+        let mut grid = Grid8::EMPTY;
+        grid.get_mut(ArrayRect::const_new::<5, 6, 2, 2>()).fill_from(SAMPLE_GRID.get(RECT_1_1_2_2));
+
+        assert_eq!(grid, array_grid!(8, 8; [(5, 6), (6, 6), (6, 7)]));
+    }
+
+    #[test]
+    #[should_panic(expected = "views must have the same size")]
+    fn fill_from_size_mismatch() {
+        let mut grid = Grid8::EMPTY;
+        grid.get_mut(RECT_1_1_2_2).fill_from(SAMPLE_GRID.get(ArrayRect::const_new::<0, 0, 3, 2>()));
+    }
+
     #[test]
     fn update_oob() {
         let mut grid = Grid8::EMPTY;
@@ -69,6 +138,18 @@ mod iter {
         points: SAMPLE_GRID.get(RECT_1_1_2_2).points().collect::<Vec<_>>()
         => [(0, 0), (1, 0), (1, 1)]
     );
+    test_ctor!(
+        global_points: SAMPLE_GRID.get(RECT_1_1_2_2).global_points().collect::<Vec<_>>()
+        => [(1, 1), (2, 1), (2, 2)]
+    );
+    test_ctor!(
+        global_points_origin: SAMPLE_GRID.get(ArrayRect::const_new::<0, 0, 8, 8>()).global_points().collect::<Vec<_>>()
+        => [(1, 1), (2, 1), (2, 2), (4, 4)]
+    );
+    test_ctor!(
+        global_points_empty: SAMPLE_GRID.get(ArrayRect::const_new::<5, 5, 3, 3>()).global_points().count()
+        => 0
+    );
     test_ctor!(
         spaces: SAMPLE_GRID.get(RECT_1_1_2_2).spaces().collect::<Vec<_>>()
         => [(0, 1)]
@@ -1,7 +1,7 @@
-use grid_mask::{Cardinal, GridMask, GridPoint, GridVector, Octile};
+use grid_mask::{Cardinal, GridMask, GridPoint, GridRect, GridVector, Octile};
 use std::str::FromStr;
 
-use crate::macros::{test_ctor, test_mutation, test_self_method};
+use crate::macros::{test_ctor, test_mutation, test_panic, test_self_method, test_transform};
 
 test_ctor!(grid_mask_new: u64::from(GridMask::from(12345)) => 12345);
 
@@ -80,6 +80,16 @@ mod pattern_data {
     ";
 }
 
+mod grid_boundary_interior {
+    use super::*;
+
+    test_ctor!(boundary_count: GridMask::GRID_BOUNDARY.count() => 28);
+    test_ctor!(interior_count: GridMask::GRID_INTERIOR.count() => 36);
+    test_ctor!(complement: !GridMask::GRID_BOUNDARY => GridMask::GRID_INTERIOR);
+    test_ctor!(boundary: GridMask::GRID_BOUNDARY => GridMask::from_fn(|x, y| x == 0 || x == 7 || y == 0 || y == 7));
+    test_ctor!(interior: GridMask::GRID_INTERIOR => GridMask::from(GridRect::const_new::<1, 1, 6, 6>()));
+}
+
 mod set_unset {
     use super::*;
 
@@ -87,6 +97,20 @@ mod set_unset {
     test_mutation!(unset: MASK_4_4 => update(POINT_4_4.0, false) => GridMask::EMPTY);
 }
 
+mod toggle {
+    use super::*;
+
+    test_transform!(set: GridMask::EMPTY => toggle(GridPoint::ORIGIN) => ORIGIN_POINT_MASK);
+    test_transform!(unset: ORIGIN_POINT_MASK => toggle(GridPoint::ORIGIN) => GridMask::EMPTY);
+    test_transform!(other: MASK_4_4 => toggle(GridPoint::MAX) => MASK_4_4 | MAX_POINT_MASK);
+    test_transform!(full: GridMask::FULL => toggle(POINT_4_4.0) => !MASK_4_4);
+
+    #[test]
+    fn twice_is_identity() {
+        GridPoint::all_values().for_each(|point| assert_eq!(MASK_4_4.toggle(point).toggle(point), MASK_4_4));
+    }
+}
+
 mod get {
     use super::*;
 
@@ -113,6 +137,114 @@ mod is_empty_is_full {
     test_self_method!(mixed_is_not_full: MASK_4_4 => is_full() => false);
 }
 
+mod symmetry {
+    use super::pattern_data::*;
+    use super::*;
+
+    test_self_method!(horizontal_empty: GridMask::EMPTY => is_symmetric_horizontal() => true);
+    test_self_method!(horizontal_full: GridMask::FULL => is_symmetric_horizontal() => true);
+    test_self_method!(horizontal_rows: GridMask::from_bytes([1, 2, 3, 4, 4, 3, 2, 1]) => is_symmetric_horizontal() => true);
+    test_self_method!(horizontal_cross: GridMask::from_str(CROSS)? => is_symmetric_horizontal() => false);
+    test_self_method!(horizontal_point: MASK_4_4 => is_symmetric_horizontal() => false);
+
+    test_self_method!(vertical_empty: GridMask::EMPTY => is_symmetric_vertical() => true);
+    test_self_method!(vertical_full: GridMask::FULL => is_symmetric_vertical() => true);
+    test_self_method!(vertical_cols: GridMask::from_bytes([0x81, 0x42, 0x24, 0x18, 0, 0xFF, 0, 0]) => is_symmetric_vertical() => true);
+    test_self_method!(vertical_cross: GridMask::from_str(CROSS)? => is_symmetric_vertical() => false);
+    test_self_method!(vertical_point: MASK_4_4 => is_symmetric_vertical() => false);
+
+    test_self_method!(main_diagonal_empty: GridMask::EMPTY => is_symmetric_main_diagonal() => true);
+    test_self_method!(main_diagonal_full: GridMask::FULL => is_symmetric_main_diagonal() => true);
+    test_self_method!(main_diagonal_cross: GridMask::from_str(CROSS)? => is_symmetric_main_diagonal() => true);
+    test_self_method!(main_diagonal_point: MASK_4_4 => is_symmetric_main_diagonal() => true);
+    test_self_method!(main_diagonal_spiral: GridMask::from_str(SPIRAL)? => is_symmetric_main_diagonal() => false);
+
+    test_self_method!(rotational_empty: GridMask::EMPTY => is_symmetric_rotational_180() => true);
+    test_self_method!(rotational_full: GridMask::FULL => is_symmetric_rotational_180() => true);
+    test_self_method!(rotational_checkerboard: GridMask::from_str(CHECKERBOARD)? => is_symmetric_rotational_180() => true);
+    test_self_method!(rotational_diagonals: GridMask::MAIN_DIAGONAL | GridMask::ANTI_DIAGONAL => is_symmetric_rotational_180() => true);
+    test_self_method!(rotational_point: MASK_4_4 => is_symmetric_rotational_180() => false);
+}
+
+mod diagonals {
+    use super::*;
+
+    test_ctor!(main_pattern: GridMask::MAIN_DIAGONAL => GridMask::from_fn(|x, y| x == y));
+    test_ctor!(anti_pattern: GridMask::ANTI_DIAGONAL => GridMask::from_fn(|x, y| x + y == 7));
+    test_ctor!(main_line: GridMask::MAIN_DIAGONAL => GridMask::line(GridPoint::ORIGIN, GridPoint::MAX));
+    test_ctor!(anti_line: GridMask::ANTI_DIAGONAL => GridMask::line(GridPoint::const_new::<7, 0>(), GridPoint::const_new::<0, 7>()));
+
+    test_self_method!(main_count: GridMask::MAIN_DIAGONAL => count() => 8);
+    test_self_method!(anti_count: GridMask::ANTI_DIAGONAL => count() => 8);
+    test_self_method!(main_transpose: GridMask::MAIN_DIAGONAL => transpose() => GridMask::MAIN_DIAGONAL);
+    test_self_method!(main_flip: GridMask::MAIN_DIAGONAL => flip_horizontal() => GridMask::ANTI_DIAGONAL);
+    test_self_method!(anti_rotate: GridMask::ANTI_DIAGONAL => rotate_90() => GridMask::MAIN_DIAGONAL);
+    test_self_method!(disjoint: GridMask::MAIN_DIAGONAL => overlaps(GridMask::ANTI_DIAGONAL) => false);
+}
+
+mod subset_superset {
+    use super::pattern_data::*;
+    use super::*;
+
+    test_self_method!(empty_subset_of_full: GridMask::EMPTY => is_subset_of(GridMask::FULL) => true);
+    test_self_method!(full_subset_of_empty: GridMask::FULL => is_subset_of(GridMask::EMPTY) => false);
+    test_self_method!(empty_subset_of_empty: GridMask::EMPTY => is_subset_of(GridMask::EMPTY) => true);
+    test_self_method!(point_subset_of_cross: MASK_4_4 => is_subset_of(GridMask::from_str(CROSS)?) => true);
+    test_self_method!(cross_subset_of_point: GridMask::from_str(CROSS)? => is_subset_of(MASK_4_4) => false);
+    test_self_method!(disjoint_subset: ORIGIN_POINT_MASK => is_subset_of(MAX_POINT_MASK) => false);
+
+    test_self_method!(full_superset_of_empty: GridMask::FULL => is_superset_of(GridMask::EMPTY) => true);
+    test_self_method!(empty_superset_of_full: GridMask::EMPTY => is_superset_of(GridMask::FULL) => false);
+    test_self_method!(full_superset_of_full: GridMask::FULL => is_superset_of(GridMask::FULL) => true);
+    test_self_method!(cross_superset_of_point: GridMask::from_str(CROSS)? => is_superset_of(MASK_4_4) => true);
+    test_self_method!(point_superset_of_cross: MASK_4_4 => is_superset_of(GridMask::from_str(CROSS)?) => false);
+    test_self_method!(disjoint_superset: ORIGIN_POINT_MASK => is_superset_of(MAX_POINT_MASK) => false);
+}
+
+mod overlaps {
+    use super::pattern_data::*;
+    use super::*;
+
+    test_self_method!(empty_full: GridMask::EMPTY => overlaps(GridMask::FULL) => false);
+    test_self_method!(empty_empty: GridMask::EMPTY => overlaps(GridMask::EMPTY) => false);
+    test_self_method!(full_full: GridMask::FULL => overlaps(GridMask::FULL) => true);
+    test_self_method!(point_cross: MASK_4_4 => overlaps(GridMask::from_str(CROSS)?) => true);
+    test_self_method!(disjoint: ORIGIN_POINT_MASK => overlaps(MAX_POINT_MASK) => false);
+    test_self_method!(checkerboard_inverse: GridMask::from_str(CHECKERBOARD)? => overlaps(!GridMask::from_str(CHECKERBOARD)?) => false);
+}
+
+mod difference {
+    use super::pattern_data::*;
+    use super::*;
+
+    test_self_method!(full_empty: GridMask::FULL => difference(GridMask::EMPTY) => GridMask::FULL);
+    test_self_method!(empty_full: GridMask::EMPTY => difference(GridMask::FULL) => GridMask::EMPTY);
+    test_self_method!(self_difference: MASK_4_4 => difference(MASK_4_4) => GridMask::EMPTY);
+    test_self_method!(cross_point: GridMask::from_str(CROSS)? => difference(MASK_4_4) => GridMask::from_str(CROSS)? & !MASK_4_4);
+    test_self_method!(disjoint: ORIGIN_POINT_MASK => difference(MAX_POINT_MASK) => ORIGIN_POINT_MASK);
+
+    test_self_method!(symmetric_full_empty: GridMask::FULL => symmetric_difference(GridMask::EMPTY) => GridMask::FULL);
+    test_self_method!(symmetric_empty_full: GridMask::EMPTY => symmetric_difference(GridMask::FULL) => GridMask::FULL);
+    test_self_method!(symmetric_self: MASK_4_4 => symmetric_difference(MASK_4_4) => GridMask::EMPTY);
+    test_self_method!(symmetric_disjoint: ORIGIN_POINT_MASK => symmetric_difference(MAX_POINT_MASK) => DISCONNECTED_MASK);
+    test_self_method!(symmetric_overlap: DISCONNECTED_MASK => symmetric_difference(MAX_POINT_MASK) => ORIGIN_POINT_MASK);
+}
+
+mod hamming {
+    use super::pattern_data::*;
+    use super::*;
+
+    test_self_method!(empty_full: GridMask::EMPTY => hamming_distance(GridMask::FULL) => 64);
+    test_self_method!(same: MASK_4_4 => hamming_distance(MASK_4_4) => 0);
+    test_self_method!(disjoint: ORIGIN_POINT_MASK => hamming_distance(MAX_POINT_MASK) => 2);
+    test_self_method!(checkerboard_inverse: GridMask::from_str(CHECKERBOARD)? => hamming_distance(!GridMask::from_str(CHECKERBOARD)?) => 64);
+    test_self_method!(cross_point: GridMask::from_str(CROSS)? => hamming_distance(MASK_4_4) => 14);
+
+    test_self_method!(similarity_empty_full: GridMask::EMPTY => hamming_similarity(GridMask::FULL) => 0.0);
+    test_self_method!(similarity_same: MASK_4_4 => hamming_similarity(MASK_4_4) => 1.0);
+    test_self_method!(similarity_half: GridMask(u64::from(u32::MAX)) => hamming_similarity(GridMask::EMPTY) => 0.5);
+}
+
 mod cell_arrays {
     use super::*;
 
@@ -180,6 +312,204 @@ mod points {
     }
 }
 
+mod from_fn {
+    use super::pattern_data::*;
+    use super::*;
+
+    test_ctor!(empty: GridMask::from_fn(|_, _| false) => GridMask::EMPTY);
+    test_ctor!(full: GridMask::from_fn(|_, _| true) => GridMask::FULL);
+    test_ctor!(point: GridMask::from_fn(|x, y| (x, y) == (4, 4)) => MASK_4_4);
+    test_ctor!(checkerboard: GridMask::from_fn(|x, y| (x + y) % 2 == 0) => GridMask::from_str(CHECKERBOARD)?);
+    test_ctor!(even_rows_cols: GridMask::from_fn(|x, y| x % 2 == 0 && y % 2 == 0) => GridMask::from_str(EVEN_ROWS_COLS)?);
+}
+
+mod line {
+    use super::*;
+
+    const SHALLOW: &str = "
+        # # . . . . . .
+        . . # # . . . .
+        . . . . # # . .
+        . . . . . . # #
+        . . . . . . . .
+        . . . . . . . .
+        . . . . . . . .
+        . . . . . . . .
+    ";
+
+    const STEEP: &str = "
+        . . . # . . . .
+        . . . # . . . .
+        . . # . . . . .
+        . . # . . . . .
+        . . # . . . . .
+        . . # . . . . .
+        . # . . . . . .
+        . # . . . . . .
+    ";
+
+    const ROW_2: &str = "
+        . . . . . . . .
+        . . . . . . . .
+        . # # # # # . .
+        . . . . . . . .
+        . . . . . . . .
+        . . . . . . . .
+        . . . . . . . .
+        . . . . . . . .
+    ";
+
+    const COL_6: &str = "
+        . . . . . . . .
+        . . . . . . # .
+        . . . . . . # .
+        . . . . . . # .
+        . . . . . . . .
+        . . . . . . . .
+        . . . . . . . .
+        . . . . . . . .
+    ";
+
+    test_ctor!(single_point: GridMask::line(POINT_4_4, POINT_4_4) => MASK_4_4);
+    test_ctor!(main_diagonal: GridMask::line(GridPoint::ORIGIN, GridPoint::MAX) => GridMask::from_fn(|x, y| x == y));
+    test_ctor!(anti_diagonal: GridMask::line(GridPoint::try_new(7, 0)?, GridPoint::try_new(0, 7)?) => GridMask::from_fn(|x, y| x + y == 7));
+    test_ctor!(horizontal: GridMask::line(GridPoint::try_new(1, 2)?, GridPoint::try_new(5, 2)?) => GridMask::from_str(ROW_2)?);
+    test_ctor!(horizontal_reversed: GridMask::line(GridPoint::try_new(5, 2)?, GridPoint::try_new(1, 2)?) => GridMask::from_str(ROW_2)?);
+    test_ctor!(vertical: GridMask::line(GridPoint::try_new(6, 1)?, GridPoint::try_new(6, 3)?) => GridMask::from_str(COL_6)?);
+    test_ctor!(vertical_reversed: GridMask::line(GridPoint::try_new(6, 3)?, GridPoint::try_new(6, 1)?) => GridMask::from_str(COL_6)?);
+    test_ctor!(shallow: GridMask::line(GridPoint::ORIGIN, GridPoint::try_new(7, 3)?) => GridMask::from_str(SHALLOW)?);
+    test_ctor!(shallow_reversed: GridMask::line(GridPoint::try_new(7, 3)?, GridPoint::ORIGIN) => GridMask::from_str(SHALLOW)?);
+    test_ctor!(steep: GridMask::line(GridPoint::try_new(1, 7)?, GridPoint::try_new(3, 0)?) => GridMask::from_str(STEEP)?);
+    test_ctor!(steep_reversed: GridMask::line(GridPoint::try_new(3, 0)?, GridPoint::try_new(1, 7)?) => GridMask::from_str(STEEP)?);
+
+    #[test]
+    fn endpoints_included() {
+        GridPoint::all_values().for_each(|start| {
+            GridPoint::all_values().for_each(|end| {
+                let line = GridMask::line(start, end);
+                assert!(line.get(start) && line.get(end), "Failed for {start} -> {end}");
+                assert!(line.is_contiguous::<Octile>(), "Failed for {start} -> {end}");
+            });
+        });
+    }
+}
+
+mod line_of_sight {
+    use super::*;
+
+    const EAST_EDGE: GridPoint = GridPoint::const_new::<7, 4>();
+    const WEST_EDGE: GridPoint = GridPoint::const_new::<0, 4>();
+
+    test_ctor!(clear: GridMask::line_of_sight(WEST_EDGE, EAST_EDGE, GridMask::EMPTY) => true);
+    test_ctor!(blocked: GridMask::line_of_sight(WEST_EDGE, EAST_EDGE, MASK_4_4) => false);
+    test_ctor!(blocked_full: GridMask::line_of_sight(GridPoint::ORIGIN, GridPoint::MAX, GridMask::FULL) => false);
+    test_ctor!(adjacent_full: GridMask::line_of_sight(GridPoint::ORIGIN, GridPoint::const_new::<1, 1>(), GridMask::FULL) => true);
+    test_ctor!(same_point: GridMask::line_of_sight(POINT_4_4, POINT_4_4, MASK_4_4) => true);
+    test_ctor!(from_endpoint: GridMask::line_of_sight(POINT_4_4, EAST_EDGE, MASK_4_4) => true);
+    test_ctor!(to_endpoint: GridMask::line_of_sight(WEST_EDGE, POINT_4_4, MASK_4_4) => true);
+    test_ctor!(off_line: GridMask::line_of_sight(GridPoint::ORIGIN, GridPoint::MAX, GridMask::ANTI_DIAGONAL) => true);
+    test_ctor!(diagonal: GridMask::line_of_sight(GridPoint::ORIGIN, GridPoint::MAX, MASK_4_4) => false);
+
+    test_ctor!(inclusive_clear: GridMask::line_of_sight_inclusive(WEST_EDGE, EAST_EDGE, GridMask::EMPTY) => true);
+    test_ctor!(inclusive_blocked: GridMask::line_of_sight_inclusive(WEST_EDGE, EAST_EDGE, MASK_4_4) => false);
+    test_ctor!(inclusive_same_point: GridMask::line_of_sight_inclusive(POINT_4_4, POINT_4_4, MASK_4_4) => false);
+    test_ctor!(inclusive_from_endpoint: GridMask::line_of_sight_inclusive(POINT_4_4, EAST_EDGE, MASK_4_4) => false);
+    test_ctor!(inclusive_to_endpoint: GridMask::line_of_sight_inclusive(WEST_EDGE, POINT_4_4, MASK_4_4) => false);
+}
+
+mod diamond_disk {
+    use super::*;
+
+    const DIAMOND_4_4_2: &str = "
+        . . . . . . . .
+        . . . . . . . .
+        . . . . # . . .
+        . . . # . # . .
+        . . # . . . # .
+        . . . # . # . .
+        . . . . # . . .
+        . . . . . . . .
+    ";
+
+    const DISK_4_4_2: &str = "
+        . . . . . . . .
+        . . . . . . . .
+        . . . . # . . .
+        . . . # # # . .
+        . . # # # # # .
+        . . . # # # . .
+        . . . . # . . .
+        . . . . . . . .
+    ";
+
+    const DIAMOND_ORIGIN_2: &str = "
+        . . # . . . . .
+        . # . . . . . .
+        # . . . . . . .
+        . . . . . . . .
+        . . . . . . . .
+        . . . . . . . .
+        . . . . . . . .
+        . . . . . . . .
+    ";
+
+    test_ctor!(diamond_zero: GridMask::diamond(GridPoint::ORIGIN, 0) => ORIGIN_POINT_MASK);
+    test_ctor!(diamond_one: GridMask::diamond(POINT_4_4, 1) => GridMask::from_str(PLUS_4_4)? & !MASK_4_4);
+    test_ctor!(diamond_two: GridMask::diamond(POINT_4_4, 2) => GridMask::from_str(DIAMOND_4_4_2)?);
+    test_ctor!(diamond_clipped: GridMask::diamond(GridPoint::ORIGIN, 2) => GridMask::from_str(DIAMOND_ORIGIN_2)?);
+    test_ctor!(diamond_beyond: GridMask::diamond(GridPoint::ORIGIN, 15) => GridMask::EMPTY);
+
+    test_ctor!(disk_zero: GridMask::disk(POINT_4_4, 0) => MASK_4_4);
+    test_ctor!(disk_one: GridMask::disk(POINT_4_4, 1) => GridMask::from_str(PLUS_4_4)?);
+    test_ctor!(disk_two: GridMask::disk(POINT_4_4, 2) => GridMask::from_str(DISK_4_4_2)?);
+    test_ctor!(disk_clipped: GridMask::disk(GridPoint::ORIGIN, 1) => GridMask::from_str(ZERO_POINT_PLUS)?);
+    test_ctor!(disk_full: GridMask::disk(GridPoint::ORIGIN, 14) => GridMask::FULL);
+}
+
+mod bytes {
+    use super::pattern_data::*;
+    use super::*;
+
+    test_ctor!(from_empty: GridMask::from_bytes([0; 8]) => GridMask::EMPTY);
+    test_ctor!(from_full: GridMask::from_bytes([0xFF; 8]) => GridMask::FULL);
+    test_ctor!(from_point: GridMask::from_bytes([0, 0, 0, 0, 0b1_0000, 0, 0, 0]) => MASK_4_4);
+    test_ctor!(from_max: GridMask::from_bytes([0, 0, 0, 0, 0, 0, 0, 0b1000_0000]) => MAX_POINT_MASK);
+
+    test_self_method!(to_empty: GridMask::EMPTY => to_bytes() => [0; 8]);
+    test_self_method!(to_full: GridMask::FULL => to_bytes() => [0xFF; 8]);
+    test_self_method!(to_point: MASK_4_4 => to_bytes() => [0, 0, 0, 0, 0b1_0000, 0, 0, 0]);
+    test_self_method!(to_checkerboard: GridMask::from_str(CHECKERBOARD)? => to_bytes() => [0x55, 0xAA, 0x55, 0xAA, 0x55, 0xAA, 0x55, 0xAA]);
+
+    #[test]
+    fn round_trip() -> Result<(), Box<dyn std::error::Error>> {
+        let masks = [GridMask::from_str(SPIRAL)?, GridMask::from_str(CROSS)?, DISCONNECTED_MASK];
+        masks.iter().for_each(|&mask| assert_eq!(GridMask::from_bytes(mask.to_bytes()), mask));
+        Ok(())
+    }
+}
+
+mod row_array {
+    use super::pattern_data::*;
+    use super::*;
+
+    test_ctor!(from_empty: GridMask::from_row_array([0; 8]) => GridMask::EMPTY);
+    test_ctor!(from_full: GridMask::from_row_array([0xFF; 8]) => GridMask::FULL);
+    test_ctor!(from_origin: GridMask::from_row_array([1, 0, 0, 0, 0, 0, 0, 0]) => ORIGIN_POINT_MASK);
+    test_ctor!(from_max: GridMask::from_row_array([0, 0, 0, 0, 0, 0, 0, 0b1000_0000]) => MAX_POINT_MASK);
+
+    test_self_method!(to_empty: GridMask::EMPTY => to_row_array() => [0; 8]);
+    test_self_method!(to_full: GridMask::FULL => to_row_array() => [0xFF; 8]);
+    test_self_method!(to_point: MASK_4_4 => to_row_array() => [0, 0, 0, 0, 0b1_0000, 0, 0, 0]);
+    test_self_method!(to_diagonal: GridMask::MAIN_DIAGONAL => to_row_array() => [1, 2, 4, 8, 16, 32, 64, 128]);
+
+    #[test]
+    fn round_trip() -> Result<(), Box<dyn std::error::Error>> {
+        let masks = [GridMask::from_str(SPIRAL)?, GridMask::from_str(CROSS)?, DISCONNECTED_MASK];
+        masks.iter().for_each(|&mask| assert_eq!(GridMask::from_row_array(mask.to_row_array()), mask));
+        Ok(())
+    }
+}
+
 mod from_bool_array {
     use super::cell_arrays::*;
     use super::*;
@@ -189,6 +519,101 @@ mod from_bool_array {
     test_ctor!(mixed: GridMask::from(MIXED_CELLS) => MIXED_MASK);
 }
 
+mod run_length {
+    use super::pattern_data::*;
+    use super::*;
+
+    const FULL_RUNS: [(u8, u8, u8); 8] =
+        [(0, 0, 8), (1, 0, 8), (2, 0, 8), (3, 0, 8), (4, 0, 8), (5, 0, 8), (6, 0, 8), (7, 0, 8)];
+
+    test_self_method!(encode_empty: GridMask::EMPTY => run_length_encode() => []);
+    test_self_method!(encode_full: GridMask::FULL => run_length_encode() => FULL_RUNS);
+    test_self_method!(encode_point: MASK_4_4 => run_length_encode() => [(4, 4, 1)]);
+    test_self_method!(encode_max: MAX_POINT_MASK => run_length_encode() => [(7, 7, 1)]);
+    test_self_method!(encode_plus: GridMask::from_str(PLUS_4_4)? => run_length_encode() => [(3, 4, 1), (4, 3, 3), (5, 4, 1)]);
+
+    test_ctor!(decode_empty: GridMask::run_length_decode(&[]) => GridMask::EMPTY);
+    test_ctor!(decode_full: GridMask::run_length_decode(&FULL_RUNS) => GridMask::FULL);
+    test_ctor!(decode_point: GridMask::run_length_decode(&[(4, 4, 1)]) => MASK_4_4);
+    test_ctor!(decode_zero_length: GridMask::run_length_decode(&[(4, 4, 0)]) => GridMask::EMPTY);
+    test_ctor!(decode_clipped: GridMask::run_length_decode(&[(7, 7, 255)]) => MAX_POINT_MASK);
+    test_ctor!(decode_out_of_range: GridMask::run_length_decode(&[(8, 0, 8), (0, 8, 1)]) => GridMask::EMPTY);
+    test_ctor!(decode_overlapping: GridMask::run_length_decode(&[(0, 0, 4), (0, 2, 4)]) => GridMask(0b11_1111));
+
+    #[test]
+    fn round_trip() -> Result<(), Box<dyn std::error::Error>> {
+        let masks = [GridMask::from_str(SPIRAL)?, GridMask::from_str(CHECKERBOARD)?, DISCONNECTED_MASK];
+        masks.iter().for_each(|&mask| assert_eq!(GridMask::run_length_decode(&mask.run_length_encode()), mask));
+        Ok(())
+    }
+}
+
+mod hex {
+    use grid_mask::err::ParseError;
+
+    use super::pattern_data::*;
+    use super::*;
+
+    test_self_method!(format_empty: GridMask::EMPTY => format_hex() => "0000000000000000");
+    test_self_method!(format_full: GridMask::FULL => format_hex() => "ffffffffffffffff");
+    test_self_method!(format_origin: ORIGIN_POINT_MASK => format_hex() => "0000000000000001");
+    test_self_method!(format_max: MAX_POINT_MASK => format_hex() => "8000000000000000");
+
+    test_ctor!(parse_empty: GridMask::from_hex("0000000000000000") => Ok(GridMask::EMPTY));
+    test_ctor!(parse_full: GridMask::from_hex("ffffffffffffffff") => Ok(GridMask::FULL));
+    test_ctor!(parse_uppercase: GridMask::from_hex("8000000000000001") => Ok(ORIGIN_POINT_MASK | MAX_POINT_MASK));
+    test_ctor!(parse_mixed_case: GridMask::from_hex("00000000000000aB") => Ok(GridMask(0xAB)));
+    test_ctor!(parse_empty_str: GridMask::from_hex("") => Err(ParseError::WrongLength(0)));
+    test_ctor!(parse_too_short: GridMask::from_hex("fffffffffffffff") => Err(ParseError::WrongLength(15)));
+    test_ctor!(parse_too_long: GridMask::from_hex("fffffffffffffffff") => Err(ParseError::WrongLength(17)));
+    test_ctor!(parse_invalid: GridMask::from_hex("0x00000000000000") => Err(ParseError::InvalidChar('x')));
+    test_ctor!(parse_sign: GridMask::from_hex("+000000000000000") => Err(ParseError::InvalidChar('+')));
+
+    #[test]
+    fn round_trip() -> Result<(), Box<dyn std::error::Error>> {
+        let masks = [GridMask::from_str(SPIRAL)?, GridMask::from_str(CROSS)?, DISCONNECTED_MASK];
+        masks.iter().try_for_each(|&mask| {
+            assert_eq!(GridMask::from_hex(&mask.format_hex())?, mask);
+            Ok(())
+        })
+    }
+}
+
+mod centroid {
+    use super::*;
+
+    test_self_method!(empty: GridMask::EMPTY => centroid() => None);
+    test_self_method!(full: GridMask::FULL => centroid() => Some((3.5, 3.5)));
+    test_self_method!(origin: ORIGIN_POINT_MASK => centroid() => Some((0.0, 0.0)));
+    test_self_method!(max: MAX_POINT_MASK => centroid() => Some((7.0, 7.0)));
+    test_self_method!(plus: GridMask::from_str(PLUS_4_4)? => centroid() => Some((4.0, 4.0)));
+    test_self_method!(corners: GridMask::from_str(SPARSE_CORNERS)? => centroid() => Some((3.5, 1.5)));
+
+    test_self_method!(rounded_empty: GridMask::EMPTY => centroid_rounded() => None);
+    test_self_method!(rounded_full: GridMask::FULL => centroid_rounded() => Some(POINT_4_4));
+    test_self_method!(rounded_origin: ORIGIN_POINT_MASK => centroid_rounded() => Some(GridPoint::ORIGIN));
+    test_self_method!(rounded_max: MAX_POINT_MASK => centroid_rounded() => Some(GridPoint::MAX));
+    test_self_method!(rounded_plus: GridMask::from_str(PLUS_4_4)? => centroid_rounded() => Some(POINT_4_4));
+    test_self_method!(rounded_down: GridMask(0b111) => centroid_rounded() => Some(GridPoint::const_new::<1, 0>()));
+}
+
+mod to_bool_array {
+    use super::cell_arrays::*;
+    use super::pattern_data::*;
+    use super::*;
+
+    test_self_method!(empty: GridMask::EMPTY => to_bool_array() => EMPTY_CELLS);
+    test_self_method!(full: GridMask::FULL => to_bool_array() => FULL_CELLS);
+    test_self_method!(mixed: MIXED_MASK => to_bool_array() => MIXED_CELLS);
+
+    #[test]
+    fn round_trip() -> Result<(), Box<dyn std::error::Error>> {
+        let masks = [GridMask::from_str(SPIRAL)?, GridMask::from_str(CROSS)?, DISCONNECTED_MASK];
+        masks.iter().for_each(|&mask| assert_eq!(GridMask::from(mask.to_bool_array()), mask));
+        Ok(())
+    }
+}
+
 mod from_bit_index_u64 {
     use super::*;
     use grid_mask::num::BitIndexU64;
@@ -328,108 +753,538 @@ mod grow {
     }
 }
 
-// NOTE: connected tests commented out - the `connected` method was made private (renamed to `contiguous`).
-// The is_contiguous tests below still work since that method is public.
-// mod connected {
-//     mod cardinal {
-//         use super::super::cell_arrays::*;
-//         use super::super::pattern_data::*;
-//         use super::super::*;
-//
-//         test_self_method!(empty: GridMask::EMPTY => connected::<Cardinal>(GridPoint::ORIGIN) => GridMask::EMPTY);
-//         test_self_method!(single_point: ORIGIN_POINT_MASK => connected::<Cardinal>(GridPoint::ORIGIN) => ORIGIN_POINT_MASK);
-//         test_self_method!(full: GridMask::FULL => connected::<Cardinal>(GridPoint::ORIGIN) => GridMask::FULL);
-//         test_self_method!(empty_cell: MIXED_MASK => connected::<Cardinal>(GridPoint::ORIGIN) => GridMask::EMPTY);
-//         // ... more tests ...
-//     }
-//
-//     mod octile {
-//         use super::super::cell_arrays::*;
-//         use super::super::pattern_data::*;
-//         use super::super::*;
-//
-//         test_self_method!(empty: GridMask::EMPTY => connected::<Octile>(GridPoint::ORIGIN) => GridMask::EMPTY);
-//         test_self_method!(full: GridMask::FULL => connected::<Octile>(GridPoint::ORIGIN) => GridMask::FULL);
-//         // ... more tests ...
-//     }
-// }
+mod grow_n {
+    use super::*;
 
-mod is_contiguous {
-    macro_rules! test_is_contiguous {
+    test_self_method!(zero: MASK_4_4 => grow_n::<Cardinal>(0) => MASK_4_4);
+    test_self_method!(empty: GridMask::EMPTY => grow_n::<Cardinal>(100) => GridMask::EMPTY);
+    test_self_method!(once_cardinal: POINT_4_4_MASK => grow_n::<Cardinal>(1) => GridMask::from_str(PLUS_4_4)?);
+    test_self_method!(once_octile: POINT_4_4_MASK => grow_n::<Octile>(1) => GridMask::from_str(SQUARE_4_4)?);
+    test_self_method!(saturated_cardinal: ORIGIN_POINT_MASK => grow_n::<Cardinal>(14) => GridMask::FULL);
+    test_self_method!(saturated_octile: ORIGIN_POINT_MASK => grow_n::<Octile>(7) => GridMask::FULL);
+    test_self_method!(unbounded: ORIGIN_POINT_MASK => grow_n::<Cardinal>(usize::MAX) => GridMask::FULL);
+
+    #[test]
+    fn matches_repeated_grow() {
+        (0..10).for_each(|n| {
+            let repeated = (0..n).fold(ORIGIN_POINT_MASK, |mask, _| mask.grow::<Cardinal>());
+            assert_eq!(ORIGIN_POINT_MASK.grow_n::<Cardinal>(n), repeated, "Failed for n = {n}");
+        });
+    }
+}
+
+mod interior {
+    use super::pattern_data::*;
+    use super::*;
+
+    test_self_method!(empty: GridMask::EMPTY => interior::<Cardinal>() => GridMask::EMPTY);
+    test_self_method!(square: GridMask::from_str(SQUARE_4_4)? => interior::<Cardinal>() => POINT_4_4_MASK);
+    test_self_method!(thin: GridMask::from_str(SPIRAL)? => interior::<Cardinal>() => GridMask::EMPTY);
+    test_self_method!(cross: GridMask::from_str(CROSS)? => interior::<Cardinal>() => MASK_4_4);
+    test_self_method!(full: GridMask::FULL => interior::<Cardinal>() => GridMask::FULL.erode::<Cardinal>());
+    test_ctor!(full_count: GridMask::FULL.interior::<Octile>().count() => 36);
+}
+
+mod frontier {
+    macro_rules! test_frontier {
         ($direction:ty> $name:ident: $mask:expr => $expected:expr) => {
-            test_self_method!($name: $mask => is_contiguous::<$direction>() => $expected);
+            test_self_method!($name: $mask => frontier::<$direction>() => $expected);
         };
     }
 
     mod cardinal {
-        use super::super::pattern_data::*;
         use super::super::*;
 
-        test_is_contiguous!(Cardinal> empty: GridMask::EMPTY => false);
-        test_is_contiguous!(Cardinal> full: GridMask::FULL => true);
-        test_is_contiguous!(Cardinal> spiral: GridMask::from_str(SPIRAL)? => true);
-        test_is_contiguous!(Cardinal> cross: GridMask::from_str(CROSS)? => true);
-        test_is_contiguous!(Cardinal> disjoint: DISCONNECTED_MASK => false);
-        test_is_contiguous!(Cardinal> checkerboard: GridMask::from_str(CHECKERBOARD)? => false);
+        test_frontier!(Cardinal> empty: GridMask::EMPTY => GridMask::EMPTY);
+        test_frontier!(Cardinal> full: GridMask::FULL => GridMask::EMPTY);
+        test_frontier!(Cardinal> center: POINT_4_4_MASK => GridMask::from_str(PLUS_4_4)? & !POINT_4_4_MASK);
+        test_frontier!(Cardinal> top_left: ORIGIN_POINT_MASK => GridMask::from_str(ZERO_POINT_PLUS)? & !ORIGIN_POINT_MASK);
     }
 
     mod octile {
-        use super::super::pattern_data::*;
         use super::super::*;
 
-        test_is_contiguous!(Octile> empty: GridMask::EMPTY => false);
-        test_is_contiguous!(Octile> full: GridMask::FULL => true);
-        test_is_contiguous!(Octile> spiral: GridMask::from_str(SPIRAL)? => true);
-        test_is_contiguous!(Octile> cross: GridMask::from_str(CROSS)? => true);
-        test_is_contiguous!(Octile> disjoint: DISCONNECTED_MASK => false);
-        test_is_contiguous!(Octile> checkerboard: GridMask::from_str(CHECKERBOARD)? => true);
+        test_frontier!(Octile> empty: GridMask::EMPTY => GridMask::EMPTY);
+        test_frontier!(Octile> full: GridMask::FULL => GridMask::EMPTY);
+        test_frontier!(Octile> center: POINT_4_4_MASK => GridMask::from_str(SQUARE_4_4)? & !POINT_4_4_MASK);
+        test_frontier!(Octile> top_left: ORIGIN_POINT_MASK => GridMask::from_str(ZERO_POINT_SQUARE)? & !ORIGIN_POINT_MASK);
     }
 }
 
-mod translate {
-    use crate::macros::test_transform;
-
+mod hit_and_miss {
     use super::*;
 
-    test_transform!(identity: MASK_4_4 => translate(GridVector::ZERO) => MASK_4_4);
+    const EAST_POINT_MASK: GridMask = GridMask(0b10);
 
-    test_transform!(east: MASK_4_4 => translate(GridVector::EAST) => mask_from_coords(5, 4));
-    test_transform!(west: MASK_4_4 => translate(GridVector::WEST) => mask_from_coords(3, 4));
-    test_transform!(south: MASK_4_4 => translate(GridVector::SOUTH) => mask_from_coords(4, 5));
-    test_transform!(north: MASK_4_4 => translate(GridVector::NORTH) => mask_from_coords(4, 3));
+    test_self_method!(empty_elements: POINT_4_4_MASK => hit_and_miss(GridMask::EMPTY, GridMask::EMPTY) => GridMask::FULL);
+    test_self_method!(hit_origin: GridMask::from_str(PLUS_4_4)? => hit_and_miss(ORIGIN_POINT_MASK, GridMask::EMPTY) => GridMask::from_str(PLUS_4_4)?);
+    test_self_method!(miss_origin: GridMask::from_str(PLUS_4_4)? => hit_and_miss(GridMask::EMPTY, ORIGIN_POINT_MASK) => !GridMask::from_str(PLUS_4_4)?);
+    test_self_method!(east_edge_point: POINT_4_4_MASK => hit_and_miss(ORIGIN_POINT_MASK, EAST_POINT_MASK) => POINT_4_4_MASK);
+    test_self_method!(east_edge_full: GridMask::FULL => hit_and_miss(ORIGIN_POINT_MASK, EAST_POINT_MASK) => GridMask::EMPTY);
+    test_self_method!(east_edge_off_grid: MAX_POINT_MASK => hit_and_miss(ORIGIN_POINT_MASK, EAST_POINT_MASK) => GridMask::EMPTY);
+    test_self_method!(plus_corner: GridMask::from_str(PLUS_4_4)? => hit_and_miss(GridMask::from_str(ZERO_POINT_PLUS)?, GridMask::EMPTY) => POINT_4_4_MASK);
 
-    test_transform!(wrap_prevention_east: MAX_POINT_MASK => translate(GridVector::EAST) => GridMask::EMPTY);
-    test_transform!(wrap_prevention_west: ORIGIN_POINT_MASK => translate(GridVector::WEST) => GridMask::EMPTY);
+    test_panic!(overlapping: POINT_4_4_MASK.hit_and_miss(ORIGIN_POINT_MASK, ORIGIN_POINT_MASK) => "hit and miss must not overlap");
+}
 
-    const OOB_SHIFTS: [GridVector; 4] = [
-        // East
-        GridVector::new(8, 0),
-        // West
-        GridVector::new(-8, 0),
-        // South
-        GridVector::new(0, 8),
-        // North
-        GridVector::new(0, -8),
-    ];
+mod grow_bounded {
+    macro_rules! test_grow_bounded {
+        ($direction:ty> $name:ident: $mask:expr, $limit:expr => $expected:expr) => {
+            test_self_method!($name: $mask => grow_bounded::<$direction>($limit) => $expected);
+        };
+    }
 
-    #[test]
-    fn oob_shifts() {
-        OOB_SHIFTS.iter().for_each(|&shift| {
-            let val = GridMask::FULL;
-            let translated = val.translate(shift);
-            assert_eq!(translated, GridMask::EMPTY, "Failed for input {:?}", shift);
-        });
+    mod cardinal {
+        use super::super::*;
+
+        test_grow_bounded!(Cardinal> empty: GridMask::EMPTY, GridMask::FULL => GridMask::EMPTY);
+        test_grow_bounded!(Cardinal> unbounded: ORIGIN_POINT_MASK, GridMask::FULL => GridMask::from_str(ZERO_POINT_PLUS)?);
+        test_grow_bounded!(Cardinal> empty_limit: POINT_4_4_MASK, GridMask::EMPTY => GridMask::EMPTY);
+        test_grow_bounded!(Cardinal> self_limit: POINT_4_4_MASK, POINT_4_4_MASK => POINT_4_4_MASK);
+        test_grow_bounded!(Cardinal> square_limit: POINT_4_4_MASK, GridMask::from_str(SQUARE_4_4)? => GridMask::from_str(PLUS_4_4)?);
     }
-}
 
-mod from_str {
-    use grid_mask::err::PatternError;
+    mod octile {
+        use super::super::*;
 
-    use super::pattern_data::*;
-    use super::*;
+        test_grow_bounded!(Octile> empty: GridMask::EMPTY, GridMask::FULL => GridMask::EMPTY);
+        test_grow_bounded!(Octile> unbounded: ORIGIN_POINT_MASK, GridMask::FULL => GridMask::from_str(ZERO_POINT_SQUARE)?);
+        test_grow_bounded!(Octile> empty_limit: POINT_4_4_MASK, GridMask::EMPTY => GridMask::EMPTY);
+        test_grow_bounded!(Octile> self_limit: POINT_4_4_MASK, POINT_4_4_MASK => POINT_4_4_MASK);
+        test_grow_bounded!(Octile> plus_limit: POINT_4_4_MASK, GridMask::from_str(PLUS_4_4)? => GridMask::from_str(PLUS_4_4)?);
+    }
+}
 
-    test_ctor!(valid: GridMask::from_str(super::POINT_4_4_PATTERN) => Ok(super::POINT_4_4_MASK));
-    test_ctor!(too_long: GridMask::from_str(TOO_LONG) => Err(PatternError::TooLong));
+mod count_neighbors {
+    macro_rules! test_count_neighbors {
+        ($direction:ty> $name:ident: $mask:expr, $point:expr => $expected:expr) => {
+            test_self_method!($name: $mask => count_neighbors::<$direction>($point) => $expected);
+        };
+    }
+
+    mod cardinal {
+        use super::super::*;
+
+        test_count_neighbors!(Cardinal> empty: GridMask::EMPTY, POINT_4_4 => 0);
+        test_count_neighbors!(Cardinal> full_center: GridMask::FULL, POINT_4_4 => 4);
+        test_count_neighbors!(Cardinal> full_corner: GridMask::FULL, GridPoint::ORIGIN => 2);
+        test_count_neighbors!(Cardinal> full_edge: GridMask::FULL, GridPoint::const_new::<0, 4>() => 3);
+        test_count_neighbors!(Cardinal> self_only: POINT_4_4_MASK, POINT_4_4 => 0);
+        test_count_neighbors!(Cardinal> diagonal: GridMask::from_str(SQUARE_4_4)?, POINT_4_4 => 4);
+    }
+
+    mod octile {
+        use super::super::*;
+
+        test_count_neighbors!(Octile> empty: GridMask::EMPTY, POINT_4_4 => 0);
+        test_count_neighbors!(Octile> full_center: GridMask::FULL, POINT_4_4 => 8);
+        test_count_neighbors!(Octile> full_corner: GridMask::FULL, GridPoint::ORIGIN => 3);
+        test_count_neighbors!(Octile> full_edge: GridMask::FULL, GridPoint::const_new::<0, 4>() => 5);
+        test_count_neighbors!(Octile> self_only: POINT_4_4_MASK, POINT_4_4 => 0);
+        test_count_neighbors!(Octile> plus: GridMask::from_str(PLUS_4_4)?, POINT_4_4 => 4);
+    }
+}
+
+mod isolated {
+    macro_rules! test_isolated {
+        ($direction:ty> $name:ident: $mask:expr => $expected:expr, $count:expr) => {
+            mod $name {
+                use super::*;
+
+                test_self_method!(cells: $mask => isolated_cells::<$direction>() => $expected);
+                test_self_method!(count: $mask => count_isolated::<$direction>() => $count);
+            }
+        };
+    }
+
+    mod cardinal {
+        use super::super::pattern_data::*;
+        use super::super::*;
+
+        test_isolated!(Cardinal> empty: GridMask::EMPTY => GridMask::EMPTY, 0);
+        test_isolated!(Cardinal> full: GridMask::FULL => GridMask::EMPTY, 0);
+        test_isolated!(Cardinal> point: POINT_4_4_MASK => POINT_4_4_MASK, 1);
+        test_isolated!(Cardinal> checkerboard: GridMask::from_str(CHECKERBOARD)? => GridMask::from_str(CHECKERBOARD)?, 32);
+        test_isolated!(Cardinal> sparse: GridMask::from_str(SPARSE_CORNERS)? => GridMask::from_str(SPARSE_CORNERS)?, 4);
+        test_isolated!(Cardinal> mixed: GridMask::from_str(PLUS_4_4)? | ORIGIN_POINT_MASK => ORIGIN_POINT_MASK, 1);
+        // (7, 0) and (0, 1) are adjacent bits, but not adjacent cells
+        test_isolated!(Cardinal> row_wrap: GridMask(1 << 7 | 1 << 8) => GridMask(1 << 7 | 1 << 8), 2);
+    }
+
+    mod octile {
+        use super::super::pattern_data::*;
+        use super::super::*;
+
+        test_isolated!(Octile> empty: GridMask::EMPTY => GridMask::EMPTY, 0);
+        test_isolated!(Octile> full: GridMask::FULL => GridMask::EMPTY, 0);
+        test_isolated!(Octile> point: POINT_4_4_MASK => POINT_4_4_MASK, 1);
+        test_isolated!(Octile> checkerboard: GridMask::from_str(CHECKERBOARD)? => GridMask::EMPTY, 0);
+        test_isolated!(Octile> sparse: GridMask::from_str(SPARSE_CORNERS)? => GridMask::from_str(SPARSE_CORNERS)?, 4);
+        test_isolated!(Octile> mixed: GridMask::from_str(PLUS_4_4)? | ORIGIN_POINT_MASK => ORIGIN_POINT_MASK, 1);
+        // (7, 0) and (0, 1) are adjacent bits, but not adjacent cells
+        test_isolated!(Octile> row_wrap: GridMask(1 << 7 | 1 << 8) => GridMask(1 << 7 | 1 << 8), 2);
+    }
+}
+
+mod perimeter {
+    macro_rules! test_perimeter {
+        ($direction:ty> $name:ident: $mask:expr => $expected:expr) => {
+            test_self_method!($name: $mask => perimeter::<$direction>() => $expected);
+        };
+    }
+
+    mod cardinal {
+        use super::super::*;
+
+        test_perimeter!(Cardinal> empty: GridMask::EMPTY => 0);
+        test_perimeter!(Cardinal> full: GridMask::FULL => 28);
+        test_perimeter!(Cardinal> point: POINT_4_4_MASK => 1);
+        test_perimeter!(Cardinal> plus: GridMask::from_str(PLUS_4_4)? => 4);
+        test_perimeter!(Cardinal> square: GridMask::from_str(SQUARE_4_4)? => 8);
+    }
+
+    mod octile {
+        use super::super::*;
+
+        test_perimeter!(Octile> empty: GridMask::EMPTY => 0);
+        test_perimeter!(Octile> full: GridMask::FULL => 28);
+        test_perimeter!(Octile> point: POINT_4_4_MASK => 1);
+        test_perimeter!(Octile> plus: GridMask::from_str(PLUS_4_4)? => 5);
+        test_perimeter!(Octile> square: GridMask::from_str(SQUARE_4_4)? => 8);
+    }
+}
+
+mod erode {
+    macro_rules! test_erode {
+        ($direction:ty> $name:ident: $mask:expr => $expected:expr) => {
+            test_self_method!($name: $mask => erode::<$direction>() => $expected);
+        };
+    }
+
+    const INNER_6_6: &str = "
+        . . . . . . . .
+        . # # # # # # .
+        . # # # # # # .
+        . # # # # # # .
+        . # # # # # # .
+        . # # # # # # .
+        . # # # # # # .
+        . . . . . . . .
+    ";
+
+    mod cardinal {
+        use super::super::*;
+        use super::INNER_6_6;
+
+        test_erode!(Cardinal> empty: GridMask::EMPTY => GridMask::EMPTY);
+        test_erode!(Cardinal> full: GridMask::FULL => GridMask::from_str(INNER_6_6)?);
+        test_erode!(Cardinal> single: POINT_4_4_MASK => GridMask::EMPTY);
+        test_erode!(Cardinal> plus: GridMask::from_str(PLUS_4_4)? => POINT_4_4_MASK);
+        test_erode!(Cardinal> square: GridMask::from_str(SQUARE_4_4)? => POINT_4_4_MASK);
+        test_erode!(Cardinal> corner: GridMask::from_str(ZERO_POINT_SQUARE)? => GridMask::EMPTY);
+    }
+
+    mod octile {
+        use super::super::*;
+        use super::INNER_6_6;
+
+        test_erode!(Octile> empty: GridMask::EMPTY => GridMask::EMPTY);
+        test_erode!(Octile> full: GridMask::FULL => GridMask::from_str(INNER_6_6)?);
+        test_erode!(Octile> single: POINT_4_4_MASK => GridMask::EMPTY);
+        test_erode!(Octile> plus: GridMask::from_str(PLUS_4_4)? => GridMask::EMPTY);
+        test_erode!(Octile> square: GridMask::from_str(SQUARE_4_4)? => POINT_4_4_MASK);
+    }
+}
+
+// NOTE: connected tests commented out - the `connected` method was made private (renamed to `contiguous`).
+// The is_contiguous tests below still work since that method is public.
+// mod connected {
+//     mod cardinal {
+//         use super::super::cell_arrays::*;
+//         use super::super::pattern_data::*;
+//         use super::super::*;
+//
+//         test_self_method!(empty: GridMask::EMPTY => connected::<Cardinal>(GridPoint::ORIGIN) => GridMask::EMPTY);
+//         test_self_method!(single_point: ORIGIN_POINT_MASK => connected::<Cardinal>(GridPoint::ORIGIN) => ORIGIN_POINT_MASK);
+//         test_self_method!(full: GridMask::FULL => connected::<Cardinal>(GridPoint::ORIGIN) => GridMask::FULL);
+//         test_self_method!(empty_cell: MIXED_MASK => connected::<Cardinal>(GridPoint::ORIGIN) => GridMask::EMPTY);
+//         // ... more tests ...
+//     }
+//
+//     mod octile {
+//         use super::super::cell_arrays::*;
+//         use super::super::pattern_data::*;
+//         use super::super::*;
+//
+//         test_self_method!(empty: GridMask::EMPTY => connected::<Octile>(GridPoint::ORIGIN) => GridMask::EMPTY);
+//         test_self_method!(full: GridMask::FULL => connected::<Octile>(GridPoint::ORIGIN) => GridMask::FULL);
+//         // ... more tests ...
+//     }
+// }
+
+mod is_contiguous {
+    macro_rules! test_is_contiguous {
+        ($direction:ty> $name:ident: $mask:expr => $expected:expr) => {
+            test_self_method!($name: $mask => is_contiguous::<$direction>() => $expected);
+        };
+    }
+
+    mod cardinal {
+        use super::super::pattern_data::*;
+        use super::super::*;
+
+        test_is_contiguous!(Cardinal> empty: GridMask::EMPTY => false);
+        test_is_contiguous!(Cardinal> full: GridMask::FULL => true);
+        test_is_contiguous!(Cardinal> spiral: GridMask::from_str(SPIRAL)? => true);
+        test_is_contiguous!(Cardinal> cross: GridMask::from_str(CROSS)? => true);
+        test_is_contiguous!(Cardinal> disjoint: DISCONNECTED_MASK => false);
+        test_is_contiguous!(Cardinal> checkerboard: GridMask::from_str(CHECKERBOARD)? => false);
+    }
+
+    mod octile {
+        use super::super::pattern_data::*;
+        use super::super::*;
+
+        test_is_contiguous!(Octile> empty: GridMask::EMPTY => false);
+        test_is_contiguous!(Octile> full: GridMask::FULL => true);
+        test_is_contiguous!(Octile> spiral: GridMask::from_str(SPIRAL)? => true);
+        test_is_contiguous!(Octile> cross: GridMask::from_str(CROSS)? => true);
+        test_is_contiguous!(Octile> disjoint: DISCONNECTED_MASK => false);
+        test_is_contiguous!(Octile> checkerboard: GridMask::from_str(CHECKERBOARD)? => true);
+    }
+}
+
+mod components {
+    macro_rules! test_count_components {
+        ($direction:ty> $name:ident: $mask:expr => $expected:expr) => {
+            test_self_method!($name: $mask => count_components::<$direction>() => $expected);
+        };
+    }
+
+    mod cardinal {
+        use super::super::pattern_data::*;
+        use super::super::*;
+
+        test_count_components!(Cardinal> empty: GridMask::EMPTY => 0);
+        test_count_components!(Cardinal> full: GridMask::FULL => 1);
+        test_count_components!(Cardinal> spiral: GridMask::from_str(SPIRAL)? => 1);
+        test_count_components!(Cardinal> disjoint: DISCONNECTED_MASK => 2);
+        test_count_components!(Cardinal> sparse: GridMask::from_str(SPARSE_CORNERS)? => 4);
+        test_count_components!(Cardinal> checkerboard: GridMask::from_str(CHECKERBOARD)? => 32);
+    }
+
+    mod octile {
+        use super::super::pattern_data::*;
+        use super::super::*;
+
+        test_count_components!(Octile> empty: GridMask::EMPTY => 0);
+        test_count_components!(Octile> full: GridMask::FULL => 1);
+        test_count_components!(Octile> spiral: GridMask::from_str(SPIRAL)? => 1);
+        test_count_components!(Octile> disjoint: DISCONNECTED_MASK => 2);
+        test_count_components!(Octile> sparse: GridMask::from_str(SPARSE_CORNERS)? => 4);
+        test_count_components!(Octile> checkerboard: GridMask::from_str(CHECKERBOARD)? => 1);
+    }
+
+    use super::pattern_data::*;
+    use super::*;
+
+    test_ctor!(disjoint_components: DISCONNECTED_MASK.components::<Cardinal>().collect::<Vec<_>>() => [ORIGIN_POINT_MASK, MAX_POINT_MASK]);
+
+    #[test]
+    fn partition() -> Result<(), Box<dyn std::error::Error>> {
+        let masks = [GridMask::from_str(CHECKERBOARD)?, GridMask::from_str(SPARSE_CORNERS)?, DISCONNECTED_MASK];
+        masks.iter().for_each(|&mask| {
+            let union = mask.components::<Cardinal>().try_fold(GridMask::EMPTY, |union, component| {
+                assert!(component.is_contiguous::<Cardinal>(), "Component should be contiguous");
+                (union & component).is_empty().then_some(union | component)
+            });
+            assert_eq!(union, Some(mask), "Components should be disjoint and cover the mask");
+        });
+        Ok(())
+    }
+
+    #[test]
+    fn fused() {
+        let mut components = ORIGIN_POINT_MASK.components::<Octile>();
+        assert_eq!(components.next(), Some(ORIGIN_POINT_MASK));
+        assert_eq!(components.next(), None);
+        assert_eq!(components.next(), None);
+    }
+}
+
+mod largest_smallest_component {
+    use super::pattern_data::*;
+    use super::*;
+
+    const BLOBS: &str = "
+        # . . . . . . .
+        . . . . . . . .
+        . . # # # . . .
+        . . # # . . . .
+        . . . . . . # #
+        . . . . . . # #
+        . . . . . . . .
+        . . . . . . . #
+    ";
+
+    const LARGEST_BLOB: &str = "
+        . . . . . . . .
+        . . . . . . . .
+        . . # # # . . .
+        . . # # . . . .
+        . . . . . . . .
+        . . . . . . . .
+        . . . . . . . .
+        . . . . . . . .
+    ";
+
+    test_self_method!(largest_empty: GridMask::EMPTY => largest_component::<Cardinal>() => GridMask::EMPTY);
+    test_self_method!(largest_full: GridMask::FULL => largest_component::<Cardinal>() => GridMask::FULL);
+    test_self_method!(largest_blobs: GridMask::from_str(BLOBS)? => largest_component::<Cardinal>() => GridMask::from_str(LARGEST_BLOB)?);
+    test_self_method!(largest_tie: DISCONNECTED_MASK => largest_component::<Cardinal>() => ORIGIN_POINT_MASK);
+    test_self_method!(largest_checkerboard: GridMask::from_str(CHECKERBOARD)? => largest_component::<Octile>() => GridMask::from_str(CHECKERBOARD)?);
+
+    test_self_method!(smallest_empty: GridMask::EMPTY => smallest_component::<Cardinal>() => GridMask::EMPTY);
+    test_self_method!(smallest_full: GridMask::FULL => smallest_component::<Cardinal>() => GridMask::FULL);
+    test_self_method!(smallest_blobs: GridMask::from_str(BLOBS)? => smallest_component::<Cardinal>() => ORIGIN_POINT_MASK);
+    test_self_method!(smallest_tie: DISCONNECTED_MASK => smallest_component::<Cardinal>() => ORIGIN_POINT_MASK);
+}
+
+mod translate {
+    use crate::macros::test_transform;
+
+    use super::*;
+
+    test_transform!(identity: MASK_4_4 => translate(GridVector::ZERO) => MASK_4_4);
+
+    test_transform!(east: MASK_4_4 => translate(GridVector::EAST) => mask_from_coords(5, 4));
+    test_transform!(west: MASK_4_4 => translate(GridVector::WEST) => mask_from_coords(3, 4));
+    test_transform!(south: MASK_4_4 => translate(GridVector::SOUTH) => mask_from_coords(4, 5));
+    test_transform!(north: MASK_4_4 => translate(GridVector::NORTH) => mask_from_coords(4, 3));
+
+    test_transform!(wrap_prevention_east: MAX_POINT_MASK => translate(GridVector::EAST) => GridMask::EMPTY);
+    test_transform!(wrap_prevention_west: ORIGIN_POINT_MASK => translate(GridVector::WEST) => GridMask::EMPTY);
+
+    const OOB_SHIFTS: [GridVector; 4] = [
+        // East
+        GridVector::new(8, 0),
+        // West
+        GridVector::new(-8, 0),
+        // South
+        GridVector::new(0, 8),
+        // North
+        GridVector::new(0, -8),
+    ];
+
+    #[test]
+    fn oob_shifts() {
+        OOB_SHIFTS.iter().for_each(|&shift| {
+            let val = GridMask::FULL;
+            let translated = val.translate(shift);
+            assert_eq!(translated, GridMask::EMPTY, "Failed for input {:?}", shift);
+        });
+    }
+}
+
+mod translate_wrapping {
+    use super::pattern_data::*;
+    use super::*;
+
+    test_self_method!(identity: MASK_4_4 => translate_wrapping(GridVector::ZERO) => MASK_4_4);
+
+    test_self_method!(east: MASK_4_4 => translate_wrapping(GridVector::EAST) => mask_from_coords(5, 4));
+    test_self_method!(west: MASK_4_4 => translate_wrapping(GridVector::WEST) => mask_from_coords(3, 4));
+    test_self_method!(south: MASK_4_4 => translate_wrapping(GridVector::SOUTH) => mask_from_coords(4, 5));
+    test_self_method!(north: MASK_4_4 => translate_wrapping(GridVector::NORTH) => mask_from_coords(4, 3));
+
+    test_self_method!(wrap_east: MAX_POINT_MASK => translate_wrapping(GridVector::EAST) => mask_from_coords(0, 7));
+    test_self_method!(wrap_west: ORIGIN_POINT_MASK => translate_wrapping(GridVector::WEST) => mask_from_coords(7, 0));
+    test_self_method!(wrap_south: MAX_POINT_MASK => translate_wrapping(GridVector::SOUTH) => mask_from_coords(7, 0));
+    test_self_method!(wrap_north: ORIGIN_POINT_MASK => translate_wrapping(GridVector::NORTH) => mask_from_coords(0, 7));
+    test_self_method!(wrap_diagonal: MAX_POINT_MASK => translate_wrapping(GridVector::SOUTH_EAST) => ORIGIN_POINT_MASK);
+
+    test_self_method!(full_cycle: MASK_4_4 => translate_wrapping(GridVector::new(8, -8)) => MASK_4_4);
+    test_self_method!(large: MASK_4_4 => translate_wrapping(GridVector::new(-125, 127)) => mask_from_coords(7, 3));
+    test_self_method!(full: GridMask::FULL => translate_wrapping(GridVector::new(3, -5)) => GridMask::FULL);
+
+    #[test]
+    fn preserves_count() -> Result<(), Box<dyn std::error::Error>> {
+        let mask = GridMask::from_str(SPIRAL)?;
+        let shifts = [GridVector::new(3, 0), GridVector::new(-5, 2), GridVector::new(7, -7)];
+        shifts.iter().for_each(|&shift| assert_eq!(mask.translate_wrapping(shift).count(), mask.count()));
+        Ok(())
+    }
+}
+
+mod shadow {
+    use super::*;
+
+    test_self_method!(empty: GridMask::EMPTY => shadow(GridVector::EAST, 3) => GridMask::EMPTY);
+    test_self_method!(zero_distance: MASK_4_4 => shadow(GridVector::EAST, 0) => GridMask::EMPTY);
+    test_self_method!(zero_direction: MASK_4_4 => shadow(GridVector::ZERO, 3) => MASK_4_4);
+    test_self_method!(east: ORIGIN_POINT_MASK => shadow(GridVector::EAST, 3) => GridMask(0b1110));
+    test_self_method!(north: MASK_4_4 => shadow(GridVector::NORTH, 2) => mask_from_coords(4, 3) | mask_from_coords(4, 2));
+    test_self_method!(diagonal: ORIGIN_POINT_MASK => shadow(GridVector::SOUTH_EAST, 255) => GridMask::MAIN_DIAGONAL & !ORIGIN_POINT_MASK);
+    test_self_method!(clipped: MASK_4_4 => shadow(GridVector::WEST, 10) => GridMask::from_bytes([0, 0, 0, 0, 0x0F, 0, 0, 0]));
+    test_self_method!(stride: ORIGIN_POINT_MASK => shadow(GridVector::new(2, 0), 3) => GridMask(0b101_0100));
+    test_self_method!(overlapping: GridMask(0b11) => shadow(GridVector::EAST, 1) => GridMask(0b110));
+}
+
+mod retain {
+    use super::pattern_data::*;
+    use super::*;
+
+    test_self_method!(empty: GridMask::EMPTY => retain(|_| true) => GridMask::EMPTY);
+    test_self_method!(keep_all: GridMask::from_str(SPIRAL)? => retain(|_| true) => GridMask::from_str(SPIRAL)?);
+    test_self_method!(keep_none: GridMask::from_str(SPIRAL)? => retain(|_| false) => GridMask::EMPTY);
+    test_self_method!(left_half: GridMask::FULL => retain(|point| point.x().get() < 4) => GridMask::from_fn(|x, _| x < 4));
+    test_self_method!(single: GridMask::from_str(PLUS_4_4)? => retain(|point| point == POINT_4_4) => POINT_4_4_MASK);
+    test_self_method!(diagonal: GridMask::from_str(CROSS)? => retain(|point| point.x() == point.y()) => POINT_4_4_MASK);
+}
+
+mod every_nth_cell {
+    use super::pattern_data::*;
+    use super::*;
+
+    test_self_method!(empty: GridMask::EMPTY => every_nth_cell(2, 0) => GridMask::EMPTY);
+    test_self_method!(every: GridMask::FULL => every_nth_cell(1, 0) => GridMask::FULL);
+    test_self_method!(even: GridMask::FULL => every_nth_cell(2, 0) => GridMask(0x5555_5555_5555_5555));
+    test_self_method!(odd: GridMask::FULL => every_nth_cell(2, 1) => GridMask(0xAAAA_AAAA_AAAA_AAAA));
+    test_self_method!(column: GridMask::FULL => every_nth_cell(8, 4) => GridMask::FULL.col_mask(4));
+    test_self_method!(diagonal: GridMask::FULL => every_nth_cell(9, 0) => GridMask::MAIN_DIAGONAL);
+    test_self_method!(offset_skips: GridMask::FULL => every_nth_cell(1, 62) => GridMask(0xC000_0000_0000_0000));
+    test_self_method!(offset_out_of_range: GridMask::FULL => every_nth_cell(1, 64) => GridMask::EMPTY);
+    test_self_method!(large_n: GridMask::FULL => every_nth_cell(255, 5) => GridMask(1 << 5));
+    test_self_method!(masked: GridMask::from_str(CROSS)? => every_nth_cell(8, 4) => GridMask::from_str(CROSS)?.col_mask(4));
+
+    test_panic!(zero: GridMask::FULL.every_nth_cell(0, 0) => "n must be non-zero");
+}
+
+mod min_max_set_point {
+    use super::pattern_data::*;
+    use super::*;
+
+    test_self_method!(min_empty: GridMask::EMPTY => min_set_point() => None);
+    test_self_method!(min_full: GridMask::FULL => min_set_point() => Some(GridPoint::ORIGIN));
+    test_self_method!(min_point: MASK_4_4 => min_set_point() => Some(POINT_4_4));
+    test_self_method!(min_plus: GridMask::from_str(PLUS_4_4)? => min_set_point() => Some(GridPoint::const_new::<4, 3>()));
+    test_self_method!(min_row_order: GridMask::from_str(CROSS)? => min_set_point() => Some(GridPoint::const_new::<4, 0>()));
+
+    test_self_method!(max_empty: GridMask::EMPTY => max_set_point() => None);
+    test_self_method!(max_full: GridMask::FULL => max_set_point() => Some(GridPoint::MAX));
+    test_self_method!(max_point: MASK_4_4 => max_set_point() => Some(POINT_4_4));
+    test_self_method!(max_plus: GridMask::from_str(PLUS_4_4)? => max_set_point() => Some(GridPoint::const_new::<4, 5>()));
+    test_self_method!(max_row_order: GridMask::from_str(CROSS)? => max_set_point() => Some(GridPoint::const_new::<4, 7>()));
+}
+
+mod from_str {
+    use grid_mask::err::PatternError;
+
+    use super::pattern_data::*;
+    use super::*;
+
+    test_ctor!(valid: GridMask::from_str(super::POINT_4_4_PATTERN) => Ok(super::POINT_4_4_MASK));
+    test_ctor!(too_long: GridMask::from_str(TOO_LONG) => Err(PatternError::TooLong));
     test_ctor!(too_short: GridMask::from_str(TOO_SHORT) => Err(PATTERN_TOO_SHORT));
     test_ctor!(empty: GridMask::from_str("") => Err(PatternError::TooShort(0)));
     test_ctor!(invalid: GridMask::from_str(INVALID) => Err(PATTERN_INVALID));
@@ -449,6 +1304,193 @@ mod occupied {
     test_self_method!(even_cols: GridMask::from_str(EVEN_ROWS_COLS)? => occupied_cols() => 0b0101_0101);
 }
 
+mod row_col_mask {
+    use super::pattern_data::*;
+    use super::*;
+
+    test_self_method!(row_empty: GridMask::EMPTY => row_mask(0) => GridMask::EMPTY);
+    test_self_method!(row_full_first: GridMask::FULL => row_mask(0) => GridMask(0xFF));
+    test_self_method!(row_full_last: GridMask::FULL => row_mask(7) => GridMask(0xFF << 56));
+    test_self_method!(row_point: MASK_4_4 => row_mask(4) => MASK_4_4);
+    test_self_method!(row_other: MASK_4_4 => row_mask(3) => GridMask::EMPTY);
+    test_self_method!(row_cross: GridMask::from_str(CROSS)? => row_mask(0) => mask_from_coords(4, 0));
+    test_self_method!(row_out_of_range: GridMask::FULL => row_mask(8) => GridMask::EMPTY);
+
+    test_self_method!(col_empty: GridMask::EMPTY => col_mask(0) => GridMask::EMPTY);
+    test_self_method!(col_full_first: GridMask::FULL => col_mask(0) => GridMask(0x0101_0101_0101_0101));
+    test_self_method!(col_full_last: GridMask::FULL => col_mask(7) => GridMask(0x8080_8080_8080_8080));
+    test_self_method!(col_point: MASK_4_4 => col_mask(4) => MASK_4_4);
+    test_self_method!(col_other: MASK_4_4 => col_mask(3) => GridMask::EMPTY);
+    test_self_method!(col_cross: GridMask::from_str(CROSS)? => col_mask(0) => mask_from_coords(0, 4));
+    test_self_method!(col_out_of_range: GridMask::FULL => col_mask(8) => GridMask::EMPTY);
+}
+
+mod count_row_col {
+    use super::pattern_data::*;
+    use super::*;
+
+    test_self_method!(row_empty: GridMask::EMPTY => count_row(0) => 0);
+    test_self_method!(row_full: GridMask::FULL => count_row(0) => 8);
+    test_self_method!(row_cross_full: GridMask::from_str(CROSS)? => count_row(4) => 8);
+    test_self_method!(row_cross_single: GridMask::from_str(CROSS)? => count_row(0) => 1);
+    test_self_method!(row_checkerboard: GridMask::from_str(CHECKERBOARD)? => count_row(3) => 4);
+    test_self_method!(row_out_of_range: GridMask::FULL => count_row(8) => 0);
+
+    test_self_method!(col_empty: GridMask::EMPTY => count_col(0) => 0);
+    test_self_method!(col_full: GridMask::FULL => count_col(7) => 8);
+    test_self_method!(col_cross_full: GridMask::from_str(CROSS)? => count_col(4) => 8);
+    test_self_method!(col_cross_single: GridMask::from_str(CROSS)? => count_col(7) => 1);
+    test_self_method!(col_checkerboard: GridMask::from_str(CHECKERBOARD)? => count_col(3) => 4);
+    test_self_method!(col_out_of_range: GridMask::FULL => count_col(8) => 0);
+}
+
+mod popcount_array {
+    use super::pattern_data::*;
+    use super::*;
+
+    test_self_method!(row_empty: GridMask::EMPTY => row_popcount_array() => [0; 8]);
+    test_self_method!(row_full: GridMask::FULL => row_popcount_array() => [8; 8]);
+    test_self_method!(row_cross: GridMask::from_str(CROSS)? => row_popcount_array() => [1, 1, 1, 1, 8, 1, 1, 1]);
+    test_self_method!(row_checkerboard: GridMask::from_str(CHECKERBOARD)? => row_popcount_array() => [4; 8]);
+    test_self_method!(row_max: MAX_POINT_MASK => row_popcount_array() => [0, 0, 0, 0, 0, 0, 0, 1]);
+
+    test_self_method!(col_empty: GridMask::EMPTY => col_popcount_array() => [0; 8]);
+    test_self_method!(col_full: GridMask::FULL => col_popcount_array() => [8; 8]);
+    test_self_method!(col_cross: GridMask::from_str(CROSS)? => col_popcount_array() => [1, 1, 1, 1, 8, 1, 1, 1]);
+    test_self_method!(col_checkerboard: GridMask::from_str(CHECKERBOARD)? => col_popcount_array() => [4; 8]);
+    test_self_method!(col_max: MAX_POINT_MASK => col_popcount_array() => [0, 0, 0, 0, 0, 0, 0, 1]);
+
+    #[test]
+    fn matches_count() -> Result<(), Box<dyn std::error::Error>> {
+        let mask = GridMask::from_str(SPIRAL)?;
+        let rows = mask.row_popcount_array();
+        let cols = mask.col_popcount_array();
+        (0..8).for_each(|i| {
+            assert_eq!(u32::from(rows[usize::from(i)]), mask.count_row(i));
+            assert_eq!(u32::from(cols[usize::from(i)]), mask.count_col(i));
+        });
+        Ok(())
+    }
+}
+
+mod count_transitions {
+    use super::pattern_data::*;
+    use super::*;
+
+    test_self_method!(row_empty: GridMask::EMPTY => count_transitions_in_row(0) => 0);
+    test_self_method!(row_full: GridMask::FULL => count_transitions_in_row(0) => 1);
+    test_self_method!(row_alternating: GridMask(0b1010_1010) => count_transitions_in_row(0) => 4);
+    test_self_method!(row_runs: GridMask::from_bytes([0, 0, 0b1100_0111, 0, 0, 0, 0, 0]) => count_transitions_in_row(2) => 2);
+    test_self_method!(row_checkerboard: GridMask::from_str(CHECKERBOARD)? => count_transitions_in_row(3) => 4);
+    test_self_method!(row_cross: GridMask::from_str(CROSS)? => count_transitions_in_row(4) => 1);
+    test_self_method!(row_out_of_range: GridMask::FULL => count_transitions_in_row(8) => 0);
+
+    test_self_method!(col_empty: GridMask::EMPTY => count_transitions_in_col(0) => 0);
+    test_self_method!(col_full: GridMask::FULL => count_transitions_in_col(7) => 1);
+    test_self_method!(col_alternating: GridMask::from_bytes([1, 0, 1, 0, 1, 0, 1, 0]) => count_transitions_in_col(0) => 4);
+    test_self_method!(col_runs: GridMask::from_bytes([1, 1, 0, 0, 1, 0, 1, 1]) => count_transitions_in_col(0) => 3);
+    test_self_method!(col_checkerboard: GridMask::from_str(CHECKERBOARD)? => count_transitions_in_col(3) => 4);
+    test_self_method!(col_cross: GridMask::from_str(CROSS)? => count_transitions_in_col(4) => 1);
+    test_self_method!(col_out_of_range: GridMask::FULL => count_transitions_in_col(8) => 0);
+}
+
+mod first_last_set {
+    use super::pattern_data::*;
+    use super::*;
+
+    test_self_method!(first_row_empty: GridMask::EMPTY => first_set_in_row(0) => None);
+    test_self_method!(first_row_full: GridMask::FULL => first_set_in_row(3) => Some(GridPoint::const_new::<0, 3>()));
+    test_self_method!(first_row_cross: GridMask::from_str(CROSS)? => first_set_in_row(0) => Some(GridPoint::const_new::<4, 0>()));
+    test_self_method!(first_row_checkerboard: GridMask::from_str(CHECKERBOARD)? => first_set_in_row(3) => Some(GridPoint::const_new::<1, 3>()));
+    test_self_method!(first_row_out_of_range: GridMask::FULL => first_set_in_row(8) => None);
+
+    test_self_method!(last_row_empty: GridMask::EMPTY => last_set_in_row(0) => None);
+    test_self_method!(last_row_full: GridMask::FULL => last_set_in_row(3) => Some(GridPoint::const_new::<7, 3>()));
+    test_self_method!(last_row_cross: GridMask::from_str(CROSS)? => last_set_in_row(0) => Some(GridPoint::const_new::<4, 0>()));
+    test_self_method!(last_row_checkerboard: GridMask::from_str(CHECKERBOARD)? => last_set_in_row(0) => Some(GridPoint::const_new::<6, 0>()));
+    test_self_method!(last_row_out_of_range: GridMask::FULL => last_set_in_row(8) => None);
+
+    test_self_method!(first_col_empty: GridMask::EMPTY => first_set_in_col(0) => None);
+    test_self_method!(first_col_full: GridMask::FULL => first_set_in_col(3) => Some(GridPoint::const_new::<3, 0>()));
+    test_self_method!(first_col_cross: GridMask::from_str(CROSS)? => first_set_in_col(0) => Some(GridPoint::const_new::<0, 4>()));
+    test_self_method!(first_col_checkerboard: GridMask::from_str(CHECKERBOARD)? => first_set_in_col(3) => Some(GridPoint::const_new::<3, 1>()));
+    test_self_method!(first_col_out_of_range: GridMask::FULL => first_set_in_col(8) => None);
+
+    test_self_method!(last_col_empty: GridMask::EMPTY => last_set_in_col(0) => None);
+    test_self_method!(last_col_full: GridMask::FULL => last_set_in_col(3) => Some(GridPoint::const_new::<3, 7>()));
+    test_self_method!(last_col_cross: GridMask::from_str(CROSS)? => last_set_in_col(0) => Some(GridPoint::const_new::<0, 4>()));
+    test_self_method!(last_col_checkerboard: GridMask::from_str(CHECKERBOARD)? => last_set_in_col(0) => Some(GridPoint::const_new::<0, 6>()));
+    test_self_method!(last_col_out_of_range: GridMask::FULL => last_set_in_col(8) => None);
+}
+
+mod row_col_update {
+    use super::pattern_data::*;
+    use super::*;
+
+    const ROW_0: GridMask = GridMask(0xFF);
+    const COL_0: GridMask = GridMask(0x0101_0101_0101_0101);
+
+    test_self_method!(set_row: GridMask::EMPTY => set_row(0) => ROW_0);
+    test_self_method!(set_row_existing: ORIGIN_POINT_MASK => set_row(0) => ROW_0);
+    test_self_method!(set_row_other: MASK_4_4 => set_row(0) => ROW_0 | MASK_4_4);
+    test_self_method!(set_row_out_of_range: MASK_4_4 => set_row(8) => MASK_4_4);
+    test_self_method!(clear_row: GridMask::FULL => clear_row(0) => !ROW_0);
+    test_self_method!(clear_row_cross: GridMask::from_str(CROSS)? => clear_row(4) => GridMask::from_str(CROSS)?.col_mask(4) & !MASK_4_4);
+    test_self_method!(clear_row_out_of_range: GridMask::FULL => clear_row(8) => GridMask::FULL);
+    test_self_method!(toggle_row: ORIGIN_POINT_MASK => toggle_row(0) => GridMask(0xFE));
+    test_self_method!(toggle_row_out_of_range: MASK_4_4 => toggle_row(8) => MASK_4_4);
+
+    test_self_method!(set_col: GridMask::EMPTY => set_col(0) => COL_0);
+    test_self_method!(set_col_existing: ORIGIN_POINT_MASK => set_col(0) => COL_0);
+    test_self_method!(set_col_other: MASK_4_4 => set_col(0) => COL_0 | MASK_4_4);
+    test_self_method!(set_col_out_of_range: MASK_4_4 => set_col(8) => MASK_4_4);
+    test_self_method!(clear_col: GridMask::FULL => clear_col(0) => !COL_0);
+    test_self_method!(clear_col_out_of_range: GridMask::FULL => clear_col(8) => GridMask::FULL);
+    test_self_method!(toggle_col: ORIGIN_POINT_MASK => toggle_col(0) => GridMask(0x0101_0101_0101_0100));
+    test_self_method!(toggle_col_out_of_range: MASK_4_4 => toggle_col(8) => MASK_4_4);
+}
+
+mod shift_row {
+    use super::pattern_data::*;
+    use super::*;
+
+    const ROWS: GridMask = GridMask::from_bytes([0x01, 0x02, 0x04, 0x08, 0x10, 0x20, 0x40, 0x80]);
+
+    test_self_method!(empty: GridMask::EMPTY => shift_row(0, 1) => GridMask::EMPTY);
+    test_self_method!(down: ROWS => shift_row(2, 3) => GridMask::from_bytes([0x01, 0x02, 0, 0x04, 0x10, 0x20, 0x40, 0x80]));
+    test_self_method!(up: ROWS => shift_row(7, 0) => GridMask::from_bytes([0x80, 0x02, 0x04, 0x08, 0x10, 0x20, 0x40, 0]));
+    test_self_method!(empty_source: MASK_4_4 => shift_row(0, 4) => GridMask::EMPTY);
+    test_self_method!(full_row: GridMask::from_str(CROSS)? => shift_row(4, 7) => GridMask::from_str(CROSS)?.clear_row(4).set_row(7));
+    test_self_method!(same_row: ROWS => shift_row(3, 3) => ROWS);
+    test_self_method!(from_out_of_range: ROWS => shift_row(8, 3) => ROWS);
+    test_self_method!(to_out_of_range: ROWS => shift_row(3, 8) => ROWS);
+
+    #[test]
+    fn drop_rows() {
+        // clearing row 7 and shifting each row above it down by one
+        let dropped = (0..7).rev().fold(ROWS.clear_row(7), |mask, row| mask.shift_row(row, row + 1));
+        assert_eq!(dropped, GridMask::from_bytes([0, 0x01, 0x02, 0x04, 0x08, 0x10, 0x20, 0x40]));
+    }
+}
+
+mod rows_cols_iter {
+    use super::pattern_data::*;
+    use super::*;
+
+    test_ctor!(rows_empty: GridMask::EMPTY.rows_iter().collect::<Vec<_>>() => [0; 8]);
+    test_ctor!(rows_full: GridMask::FULL.rows_iter().collect::<Vec<_>>() => [0xFF; 8]);
+    test_ctor!(rows_cross: GridMask::from_str(CROSS)?.rows_iter().collect::<Vec<_>>() => [0x10, 0x10, 0x10, 0x10, 0xFF, 0x10, 0x10, 0x10]);
+    test_ctor!(rows_len: GridMask::EMPTY.rows_iter().len() => 8);
+
+    test_ctor!(cols_empty: GridMask::EMPTY.cols_iter().collect::<Vec<_>>() => [0; 8]);
+    test_ctor!(cols_full: GridMask::FULL.cols_iter().collect::<Vec<_>>() => [0xFF; 8]);
+    test_ctor!(cols_cross: GridMask::from_str(CROSS)?.cols_iter().collect::<Vec<_>>() => [0x10, 0x10, 0x10, 0x10, 0xFF, 0x10, 0x10, 0x10]);
+    test_ctor!(cols_len: GridMask::EMPTY.cols_iter().len() => 8);
+
+    test_ctor!(first_full_row: GridMask::from_str(CROSS)?.rows_iter().position(|row| row == 0xFF) => Some(4));
+    test_ctor!(last_col: MAX_POINT_MASK.cols_iter().next_back() => Some(0b1000_0000));
+}
+
 mod bounds {
     use super::*;
     use grid_mask::GridRect;
@@ -468,3 +1510,194 @@ mod bounds {
     test_bounds!(sw_ne_corners: GridMask(1 << 56 | 1 << 7) => Some(GridRect::MAX));
     test_bounds!(sparse_corners: GridMask::from_str(SPARSE_CORNERS)? => Some(GridRect::const_new::<2, 0, 4, 4>()));
 }
+
+mod rotate {
+    use crate::macros::test_transform;
+
+    use super::*;
+
+    const L_SHAPE: &str = "
+        # . . . . . . .
+        # . . . . . . .
+        # # . . . . . .
+        . . . . . . . .
+        . . . . . . . .
+        . . . . . . . .
+        . . . . . . . .
+        . . . . . . . .
+    ";
+
+    const L_SHAPE_90: &str = "
+        . . . . . # # #
+        . . . . . # . .
+        . . . . . . . .
+        . . . . . . . .
+        . . . . . . . .
+        . . . . . . . .
+        . . . . . . . .
+        . . . . . . . .
+    ";
+
+    const L_SHAPE_180: &str = "
+        . . . . . . . .
+        . . . . . . . .
+        . . . . . . . .
+        . . . . . . . .
+        . . . . . . . .
+        . . . . . . # #
+        . . . . . . . #
+        . . . . . . . #
+    ";
+
+    const L_SHAPE_270: &str = "
+        . . . . . . . .
+        . . . . . . . .
+        . . . . . . . .
+        . . . . . . . .
+        . . . . . . . .
+        . . . . . . . .
+        . . # . . . . .
+        # # # . . . . .
+    ";
+
+    test_transform!(rotate_90_empty: GridMask::EMPTY => rotate_90() => GridMask::EMPTY);
+    test_transform!(rotate_90_full: GridMask::FULL => rotate_90() => GridMask::FULL);
+    test_transform!(rotate_90_origin: ORIGIN_POINT_MASK => rotate_90() => mask_from_coords(7, 0));
+    test_transform!(rotate_90_shape: GridMask::from_str(L_SHAPE)? => rotate_90() => GridMask::from_str(L_SHAPE_90)?);
+
+    test_transform!(rotate_180_empty: GridMask::EMPTY => rotate_180() => GridMask::EMPTY);
+    test_transform!(rotate_180_full: GridMask::FULL => rotate_180() => GridMask::FULL);
+    test_transform!(rotate_180_origin: ORIGIN_POINT_MASK => rotate_180() => MAX_POINT_MASK);
+    test_transform!(rotate_180_shape: GridMask::from_str(L_SHAPE)? => rotate_180() => GridMask::from_str(L_SHAPE_180)?);
+
+    test_transform!(rotate_270_empty: GridMask::EMPTY => rotate_270() => GridMask::EMPTY);
+    test_transform!(rotate_270_full: GridMask::FULL => rotate_270() => GridMask::FULL);
+    test_transform!(rotate_270_origin: ORIGIN_POINT_MASK => rotate_270() => mask_from_coords(0, 7));
+    test_transform!(rotate_270_shape: GridMask::from_str(L_SHAPE)? => rotate_270() => GridMask::from_str(L_SHAPE_270)?);
+
+    #[test]
+    fn round_trip() -> Result<(), Box<dyn std::error::Error>> {
+        let masks = [GridMask::from_str(L_SHAPE)?, GridMask::from_str(pattern_data::SPIRAL)?, MASK_4_4];
+        masks.iter().for_each(|&mask| {
+            assert_eq!(mask.rotate_90().rotate_90().rotate_90().rotate_90(), mask);
+            assert_eq!(mask.rotate_90().rotate_90(), mask.rotate_180());
+            assert_eq!(mask.rotate_90().rotate_180(), mask.rotate_270());
+        });
+        Ok(())
+    }
+}
+
+mod flip {
+    use crate::macros::test_transform;
+
+    use super::*;
+
+    const ASYMMETRIC: &str = "
+        # # # . . . . .
+        # . . . . . . .
+        . . . . . . . .
+        . . . . . . . .
+        . . . . . . . .
+        . . . . . . . .
+        . . . . . . . .
+        . . . . . . . #
+    ";
+
+    const ASYMMETRIC_HORIZONTAL: &str = "
+        . . . . . # # #
+        . . . . . . . #
+        . . . . . . . .
+        . . . . . . . .
+        . . . . . . . .
+        . . . . . . . .
+        . . . . . . . .
+        # . . . . . . .
+    ";
+
+    const ASYMMETRIC_VERTICAL: &str = "
+        . . . . . . . #
+        . . . . . . . .
+        . . . . . . . .
+        . . . . . . . .
+        . . . . . . . .
+        . . . . . . . .
+        # . . . . . . .
+        # # # . . . . .
+    ";
+
+    test_transform!(horizontal_empty: GridMask::EMPTY => flip_horizontal() => GridMask::EMPTY);
+    test_transform!(horizontal_full: GridMask::FULL => flip_horizontal() => GridMask::FULL);
+    test_transform!(horizontal_origin: ORIGIN_POINT_MASK => flip_horizontal() => mask_from_coords(7, 0));
+    test_transform!(horizontal_shape: GridMask::from_str(ASYMMETRIC)? => flip_horizontal() => GridMask::from_str(ASYMMETRIC_HORIZONTAL)?);
+
+    test_transform!(vertical_empty: GridMask::EMPTY => flip_vertical() => GridMask::EMPTY);
+    test_transform!(vertical_full: GridMask::FULL => flip_vertical() => GridMask::FULL);
+    test_transform!(vertical_origin: ORIGIN_POINT_MASK => flip_vertical() => mask_from_coords(0, 7));
+    test_transform!(vertical_shape: GridMask::from_str(ASYMMETRIC)? => flip_vertical() => GridMask::from_str(ASYMMETRIC_VERTICAL)?);
+
+    #[test]
+    fn self_inverse() -> Result<(), Box<dyn std::error::Error>> {
+        let masks = [GridMask::from_str(ASYMMETRIC)?, GridMask::from_str(pattern_data::SPIRAL)?, MASK_4_4];
+        masks.iter().for_each(|&mask| {
+            assert_eq!(mask.flip_horizontal().flip_horizontal(), mask);
+            assert_eq!(mask.flip_vertical().flip_vertical(), mask);
+            assert_eq!(mask.flip_horizontal().flip_vertical(), mask.rotate_180());
+        });
+        Ok(())
+    }
+}
+
+mod transpose {
+    use crate::macros::test_transform;
+
+    use super::*;
+
+    const DIAGONAL: &str = "
+        # . . . . . . .
+        . # . . . . . .
+        . . # . . . . .
+        . . . # . . . .
+        . . . . # . . .
+        . . . . . # . .
+        . . . . . . # .
+        . . . . . . . #
+    ";
+
+    const TOP_ROW: &str = "
+        # # # # # # # #
+        . . . . . . . .
+        . . . . . . . .
+        . . . . . . . .
+        . . . . . . . .
+        . . . . . . . .
+        . . . . . . . .
+        . . . . . . . .
+    ";
+
+    const LEFT_COL: &str = "
+        # . . . . . . .
+        # . . . . . . .
+        # . . . . . . .
+        # . . . . . . .
+        # . . . . . . .
+        # . . . . . . .
+        # . . . . . . .
+        # . . . . . . .
+    ";
+
+    test_transform!(empty: GridMask::EMPTY => transpose() => GridMask::EMPTY);
+    test_transform!(full: GridMask::FULL => transpose() => GridMask::FULL);
+    test_transform!(diagonal: GridMask::from_str(DIAGONAL)? => transpose() => GridMask::from_str(DIAGONAL)?);
+    test_transform!(row_to_col: GridMask::from_str(TOP_ROW)? => transpose() => GridMask::from_str(LEFT_COL)?);
+    test_transform!(point: mask_from_coords(5, 2) => transpose() => mask_from_coords(2, 5));
+
+    #[test]
+    fn composition() -> Result<(), Box<dyn std::error::Error>> {
+        let masks = [GridMask::from_str(pattern_data::SPIRAL)?, GridMask::from_str(SPARSE_CORNERS)?, MASK_4_4];
+        masks.iter().for_each(|&mask| {
+            assert_eq!(mask.transpose().transpose(), mask);
+            assert_eq!(mask.transpose().flip_horizontal(), mask.rotate_90());
+        });
+        Ok(())
+    }
+}
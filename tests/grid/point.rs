@@ -2,6 +2,10 @@ use grid_mask::err::OutOfBounds;
 use grid_mask::num::GridPos;
 use grid_mask::{GridMask, GridPoint, GridSize};
 
+use crate::macros::{test_ctor, test_self_method};
+
+const CENTER: GridPoint = GridPoint::const_new::<4, 4>();
+
 #[test]
 fn test_partial_eq_tuple() {
     let p = GridPoint::new(GridPos::new(2).unwrap(), GridPos::new(3).unwrap());
@@ -93,3 +97,129 @@ fn test_const_new() {
     const P2: GridPoint = GridPoint::const_new::<7, 7>();
     assert_eq!(P2, (7, 7));
 }
+
+mod neighbors {
+    use super::*;
+    use grid_mask::{Cardinal, Octile};
+
+    fn mask_of(points: &[(u8, u8)]) -> GridMask {
+        points.iter().map(|&point| GridPoint::try_from(point).unwrap()).collect()
+    }
+
+    test_self_method!(cardinal_origin: GridPoint::ORIGIN => neighbors::<Cardinal>() => mask_of(&[(1, 0), (0, 1)]));
+    test_self_method!(cardinal_max: GridPoint::MAX => neighbors::<Cardinal>() => mask_of(&[(6, 7), (7, 6)]));
+    test_self_method!(cardinal_edge: GridPoint::const_new::<0, 4>() => neighbors::<Cardinal>() => mask_of(&[(0, 3), (1, 4), (0, 5)]));
+    test_self_method!(cardinal_center: CENTER => neighbors::<Cardinal>() => mask_of(&[(4, 3), (3, 4), (5, 4), (4, 5)]));
+
+    test_self_method!(octile_origin: GridPoint::ORIGIN => neighbors::<Octile>() => mask_of(&[(1, 0), (0, 1), (1, 1)]));
+    test_self_method!(octile_max: GridPoint::MAX => neighbors::<Octile>() => mask_of(&[(6, 6), (7, 6), (6, 7)]));
+    test_self_method!(octile_edge: GridPoint::const_new::<0, 4>() => neighbors::<Octile>() => mask_of(&[(0, 3), (1, 3), (1, 4), (0, 5), (1, 5)]));
+    test_self_method!(octile_center: CENTER => neighbors::<Octile>() => mask_of(&[(3, 3), (4, 3), (5, 3), (3, 4), (5, 4), (3, 5), (4, 5), (5, 5)]));
+}
+
+mod distance {
+    use super::*;
+
+    test_self_method!(manhattan_self: CENTER => manhattan_distance(CENTER) => 0);
+    test_self_method!(manhattan_max: GridPoint::ORIGIN => manhattan_distance(GridPoint::MAX) => 14);
+    test_self_method!(manhattan_symmetric: GridPoint::MAX => manhattan_distance(GridPoint::ORIGIN) => 14);
+    test_self_method!(manhattan_row: GridPoint::ORIGIN => manhattan_distance(GridPoint::const_new::<7, 0>()) => 7);
+    test_self_method!(manhattan_mixed: CENTER => manhattan_distance(GridPoint::const_new::<1, 6>()) => 5);
+
+    test_self_method!(chebyshev_self: CENTER => chebyshev_distance(CENTER) => 0);
+    test_self_method!(chebyshev_max: GridPoint::ORIGIN => chebyshev_distance(GridPoint::MAX) => 7);
+    test_self_method!(chebyshev_symmetric: GridPoint::MAX => chebyshev_distance(GridPoint::ORIGIN) => 7);
+    test_self_method!(chebyshev_row: GridPoint::ORIGIN => chebyshev_distance(GridPoint::const_new::<7, 0>()) => 7);
+    test_self_method!(chebyshev_mixed: CENTER => chebyshev_distance(GridPoint::const_new::<1, 6>()) => 3);
+}
+
+mod ops {
+    use super::*;
+    use grid_mask::GridVector;
+
+    test_ctor!(add_zero: CENTER + GridVector::ZERO => Ok(CENTER));
+    test_ctor!(add_east: CENTER + GridVector::EAST => Ok(GridPoint::const_new::<5, 4>()));
+    test_ctor!(add_north_west: CENTER + GridVector::NORTH_WEST => Ok(GridPoint::const_new::<3, 3>()));
+    test_ctor!(add_to_max: GridPoint::ORIGIN + GridVector::new(7, 7) => Ok(GridPoint::MAX));
+    test_ctor!(add_oob_x: GridPoint::MAX + GridVector::EAST => Err(OutOfBounds));
+    test_ctor!(add_oob_y: GridPoint::ORIGIN + GridVector::NORTH => Err(OutOfBounds));
+    test_ctor!(add_oob_large: GridPoint::ORIGIN + GridVector::new(i8::MAX, i8::MIN) => Err(OutOfBounds));
+
+    test_ctor!(sub_self: CENTER - CENTER => GridVector::ZERO);
+    test_ctor!(sub_max: GridPoint::MAX - GridPoint::ORIGIN => GridVector::new(7, 7));
+    test_ctor!(sub_origin: GridPoint::ORIGIN - GridPoint::MAX => GridVector::new(-7, -7));
+    test_ctor!(sub_mixed: GridPoint::const_new::<1, 6>() - CENTER => GridVector::new(-3, 2));
+
+    #[test]
+    fn add_sub_round_trip() {
+        GridPoint::all_values().for_each(|point| assert_eq!(CENTER + (point - CENTER), Ok(point)));
+    }
+}
+
+mod linear_index {
+    use super::*;
+
+    test_ctor!(from_zero: GridPoint::from_linear_index(0) => Ok(GridPoint::ORIGIN));
+    test_ctor!(from_max: GridPoint::from_linear_index(63) => Ok(GridPoint::MAX));
+    test_ctor!(from_row_end: GridPoint::from_linear_index(7) => Ok(GridPoint::const_new::<7, 0>()));
+    test_ctor!(from_row_start: GridPoint::from_linear_index(8) => Ok(GridPoint::const_new::<0, 1>()));
+    test_ctor!(from_oob: GridPoint::from_linear_index(64) => Err(OutOfBounds));
+    test_ctor!(from_oob_max: GridPoint::from_linear_index(u8::MAX) => Err(OutOfBounds));
+
+    test_self_method!(to_zero: GridPoint::ORIGIN => to_linear_index() => 0);
+    test_self_method!(to_max: GridPoint::MAX => to_linear_index() => 63);
+    test_self_method!(to_mixed: GridPoint::const_new::<5, 3>() => to_linear_index() => 29);
+
+    #[test]
+    fn round_trip() {
+        (0..64)
+            .for_each(|index| assert_eq!(GridPoint::from_linear_index(index).map(|p| p.to_linear_index()), Ok(index)));
+    }
+}
+
+mod all_between {
+    use super::*;
+
+    fn points(from: GridPoint, to: GridPoint) -> Vec<(u8, u8)> {
+        from.all_between(to).map(<(u8, u8)>::from).collect()
+    }
+
+    test_ctor!(single: points(CENTER, CENTER) => [(4, 4)]);
+    test_ctor!(diagonal: points(GridPoint::ORIGIN, GridPoint::MAX) => (0..8).map(|i| (i, i)).collect::<Vec<_>>());
+    test_ctor!(east: points(CENTER, GridPoint::const_new::<7, 4>()) => [(4, 4), (5, 4), (6, 4), (7, 4)]);
+    test_ctor!(west: points(CENTER, GridPoint::const_new::<1, 4>()) => [(4, 4), (3, 4), (2, 4), (1, 4)]);
+    test_ctor!(south: points(CENTER, GridPoint::const_new::<4, 6>()) => [(4, 4), (4, 5), (4, 6)]);
+    test_ctor!(north: points(CENTER, GridPoint::const_new::<4, 2>()) => [(4, 4), (4, 3), (4, 2)]);
+
+    // one line into each octant, shallow and steep in each quadrant
+    test_ctor!(octant_ese: points(CENTER, GridPoint::const_new::<7, 5>()) => [(4, 4), (5, 4), (6, 5), (7, 5)]);
+    test_ctor!(octant_sse: points(CENTER, GridPoint::const_new::<5, 7>()) => [(4, 4), (4, 5), (5, 6), (5, 7)]);
+    test_ctor!(octant_ssw: points(CENTER, GridPoint::const_new::<3, 7>()) => [(4, 4), (4, 5), (3, 6), (3, 7)]);
+    test_ctor!(octant_wsw: points(CENTER, GridPoint::const_new::<1, 5>()) => [(4, 4), (3, 4), (2, 5), (1, 5)]);
+    test_ctor!(octant_wnw: points(CENTER, GridPoint::const_new::<1, 3>()) => [(4, 4), (3, 4), (2, 3), (1, 3)]);
+    test_ctor!(octant_nnw: points(CENTER, GridPoint::const_new::<3, 1>()) => [(4, 4), (4, 3), (3, 2), (3, 1)]);
+    test_ctor!(octant_nne: points(CENTER, GridPoint::const_new::<5, 1>()) => [(4, 4), (4, 3), (5, 2), (5, 1)]);
+    test_ctor!(octant_ene: points(CENTER, GridPoint::const_new::<7, 3>()) => [(4, 4), (5, 4), (6, 3), (7, 3)]);
+
+    #[test]
+    fn matches_line() {
+        GridPoint::all_values().for_each(|to| {
+            let from = GridPoint::const_new::<2, 5>();
+            assert_eq!(from.all_between(to).collect::<GridMask>(), GridMask::line(from, to));
+        });
+    }
+
+    #[test]
+    fn exact_len() {
+        GridPoint::all_values().for_each(|to| {
+            let mut iter = GridPoint::ORIGIN.all_between(to);
+            while iter.len() > 0 {
+                let len = iter.len();
+                assert!(iter.next().is_some());
+                assert_eq!(iter.len(), len - 1);
+            }
+            assert_eq!(iter.next(), None);
+            assert_eq!(iter.next(), None);
+        });
+    }
+}
@@ -1 +1,234 @@
 // tests for GridRect
+use grid_mask::{GridMask, GridPoint, GridRect};
+
+use crate::macros::{test_ctor, test_self_method};
+
+const RECT_2_2_3_3: GridRect = GridRect::const_new::<2, 2, 3, 3>();
+const CENTER: GridPoint = GridPoint::const_new::<4, 4>();
+
+mod contains_point {
+    use super::*;
+
+    test_self_method!(max_origin: GridRect::MAX => contains_point(GridPoint::ORIGIN) => true);
+    test_self_method!(max_max: GridRect::MAX => contains_point(GridPoint::MAX) => true);
+    test_self_method!(top_left: RECT_2_2_3_3 => contains_point(GridPoint::const_new::<2, 2>()) => true);
+    test_self_method!(bottom_right: RECT_2_2_3_3 => contains_point(GridPoint::const_new::<4, 4>()) => true);
+    test_self_method!(left: RECT_2_2_3_3 => contains_point(GridPoint::const_new::<1, 3>()) => false);
+    test_self_method!(right: RECT_2_2_3_3 => contains_point(GridPoint::const_new::<5, 3>()) => false);
+    test_self_method!(above: RECT_2_2_3_3 => contains_point(GridPoint::const_new::<3, 1>()) => false);
+    test_self_method!(below: RECT_2_2_3_3 => contains_point(GridPoint::const_new::<3, 5>()) => false);
+
+    #[test]
+    fn matches_mask() {
+        let mask = GridMask::from(RECT_2_2_3_3);
+        GridPoint::all_values().for_each(|point| assert_eq!(RECT_2_2_3_3.contains_point(point), mask.get(point)));
+    }
+}
+
+mod contains_rect {
+    use super::*;
+
+    test_self_method!(max_self: GridRect::MAX => contains_rect(GridRect::MAX) => true);
+    test_self_method!(max_inner: GridRect::MAX => contains_rect(GridRect::const_new::<1, 1, 2, 2>()) => true);
+    test_self_method!(max_corner: GridRect::MAX => contains_rect(GridRect::const_new::<7, 7, 1, 1>()) => true);
+    test_self_method!(inner_max: RECT_2_2_3_3 => contains_rect(GridRect::MAX) => false);
+    test_self_method!(cell: RECT_2_2_3_3 => contains_rect(GridRect::const_new::<4, 4, 1, 1>()) => true);
+    test_self_method!(overlap_left: RECT_2_2_3_3 => contains_rect(GridRect::const_new::<1, 2, 2, 2>()) => false);
+    test_self_method!(overlap_right: RECT_2_2_3_3 => contains_rect(GridRect::const_new::<4, 2, 2, 2>()) => false);
+    test_self_method!(overlap_top: RECT_2_2_3_3 => contains_rect(GridRect::const_new::<2, 1, 2, 2>()) => false);
+    test_self_method!(overlap_bottom: RECT_2_2_3_3 => contains_rect(GridRect::const_new::<2, 4, 2, 2>()) => false);
+    test_self_method!(disjoint: RECT_2_2_3_3 => contains_rect(GridRect::const_new::<6, 6, 2, 2>()) => false);
+}
+
+mod intersection {
+    use super::*;
+
+    test_self_method!(max_self: GridRect::MAX => intersection(GridRect::MAX) => Some(GridRect::MAX));
+    test_self_method!(max_inner: GridRect::MAX => intersection(GridRect::const_new::<2, 2, 4, 4>()) => Some(GridRect::const_new::<2, 2, 4, 4>()));
+    test_self_method!(inner_max: RECT_2_2_3_3 => intersection(GridRect::MAX) => Some(RECT_2_2_3_3));
+    test_self_method!(overlap: RECT_2_2_3_3 => intersection(GridRect::const_new::<3, 0, 4, 4>()) => Some(GridRect::const_new::<3, 2, 2, 2>()));
+    test_self_method!(corner: RECT_2_2_3_3 => intersection(GridRect::const_new::<4, 4, 4, 4>()) => Some(GridRect::const_new::<4, 4, 1, 1>()));
+    test_self_method!(cross: GridRect::const_new::<0, 3, 8, 2>() => intersection(GridRect::const_new::<3, 0, 2, 8>()) => Some(GridRect::const_new::<3, 3, 2, 2>()));
+    test_self_method!(touching: RECT_2_2_3_3 => intersection(GridRect::const_new::<5, 2, 3, 3>()) => None);
+    test_self_method!(disjoint: RECT_2_2_3_3 => intersection(GridRect::const_new::<6, 6, 2, 2>()) => None);
+
+    #[test]
+    fn matches_mask() {
+        let other = GridRect::const_new::<3, 1, 5, 2>();
+        let expected = GridMask::from(RECT_2_2_3_3) & GridMask::from(other);
+        assert_eq!(RECT_2_2_3_3.intersection(other).map_or(GridMask::EMPTY, GridMask::from), expected);
+    }
+}
+
+mod expand_shrink {
+    use grid_mask::err::OutOfBounds;
+
+    use super::*;
+
+    test_self_method!(expand_zero: RECT_2_2_3_3 => expand(0) => Ok(RECT_2_2_3_3));
+    test_self_method!(expand_one: RECT_2_2_3_3 => expand(1) => Ok(GridRect::const_new::<1, 1, 5, 5>()));
+    test_self_method!(expand_to_max: GridRect::const_new::<1, 1, 6, 6>() => expand(1) => Ok(GridRect::MAX));
+    test_self_method!(expand_max: GridRect::MAX => expand(1) => Err(OutOfBounds));
+    test_self_method!(expand_oob_left: GridRect::const_new::<0, 3, 2, 2>() => expand(1) => Err(OutOfBounds));
+    test_self_method!(expand_oob_right: GridRect::const_new::<6, 3, 2, 2>() => expand(1) => Err(OutOfBounds));
+    test_self_method!(expand_oob_large: RECT_2_2_3_3 => expand(u8::MAX) => Err(OutOfBounds));
+
+    test_self_method!(shrink_zero: RECT_2_2_3_3 => shrink(0) => Some(RECT_2_2_3_3));
+    test_self_method!(shrink_one: RECT_2_2_3_3 => shrink(1) => Some(GridRect::const_new::<3, 3, 1, 1>()));
+    test_self_method!(shrink_max: GridRect::MAX => shrink(1) => Some(GridRect::const_new::<1, 1, 6, 6>()));
+    test_self_method!(shrink_max_three: GridRect::MAX => shrink(3) => Some(GridRect::const_new::<3, 3, 2, 2>()));
+    test_self_method!(shrink_empty: RECT_2_2_3_3 => shrink(2) => None);
+    test_self_method!(shrink_narrow: GridRect::const_new::<0, 0, 2, 8>() => shrink(1) => None);
+    test_self_method!(shrink_large: GridRect::MAX => shrink(u8::MAX) => None);
+
+    #[test]
+    fn round_trip() {
+        let rect = GridRect::const_new::<1, 2, 5, 4>();
+        assert_eq!(rect.shrink(1).map(|inner| inner.expand(1)), Some(Ok(rect)));
+    }
+}
+
+mod perimeter_mask {
+    use super::*;
+
+    test_self_method!(max: GridRect::MAX => perimeter_mask() => GridMask::GRID_BOUNDARY);
+    test_self_method!(cell: GridRect::const_new::<4, 4, 1, 1>() => perimeter_mask() => GridMask::from(GridRect::const_new::<4, 4, 1, 1>()));
+    test_self_method!(two_by_two: GridRect::const_new::<1, 1, 2, 2>() => perimeter_mask() => GridMask::from(GridRect::const_new::<1, 1, 2, 2>()));
+    test_self_method!(line: GridRect::const_new::<0, 3, 8, 1>() => perimeter_mask() => GridMask::from(GridRect::const_new::<0, 3, 8, 1>()));
+    test_self_method!(three_by_three: RECT_2_2_3_3 => perimeter_mask() => GridMask::from(RECT_2_2_3_3).with(GridPoint::const_new::<3, 3>(), false));
+    test_ctor!(count: GridRect::const_new::<1, 2, 5, 4>().perimeter_mask().count() => 14);
+}
+
+mod center_point {
+    use super::*;
+
+    test_self_method!(max: GridRect::MAX => center_point() => GridPoint::const_new::<4, 4>());
+    test_self_method!(odd: RECT_2_2_3_3 => center_point() => GridPoint::const_new::<3, 3>());
+    test_self_method!(cell: GridRect::const_new::<7, 7, 1, 1>() => center_point() => GridPoint::MAX);
+    test_self_method!(two_by_two: GridRect::const_new::<0, 0, 2, 2>() => center_point() => GridPoint::const_new::<1, 1>());
+    test_self_method!(mixed: GridRect::const_new::<1, 2, 5, 4>() => center_point() => GridPoint::const_new::<3, 4>());
+    test_self_method!(column: GridRect::const_new::<7, 0, 1, 8>() => center_point() => GridPoint::const_new::<7, 4>());
+}
+
+mod points {
+    use super::*;
+
+    fn points(rect: GridRect) -> Vec<(u8, u8)> {
+        rect.points().map(<(u8, u8)>::from).collect()
+    }
+
+    test_ctor!(cell: points(GridRect::const_new::<7, 7, 1, 1>()) => [(7, 7)]);
+    test_ctor!(two_by_two: points(GridRect::const_new::<0, 0, 2, 2>()) => [(0, 0), (1, 0), (0, 1), (1, 1)]);
+    test_ctor!(row: points(GridRect::const_new::<5, 3, 3, 1>()) => [(5, 3), (6, 3), (7, 3)]);
+    test_ctor!(col: points(GridRect::const_new::<2, 5, 1, 3>()) => [(2, 5), (2, 6), (2, 7)]);
+    test_ctor!(max: GridRect::MAX.points().collect::<Vec<_>>() => GridPoint::all_values().collect::<Vec<_>>());
+    test_ctor!(rev: GridRect::const_new::<3, 3, 2, 2>().points().rev().map(<(u8, u8)>::from).collect::<Vec<_>>() => [(4, 4), (3, 4), (4, 3), (3, 3)]);
+    test_ctor!(into_iter: GridRect::const_new::<1, 2, 5, 4>().into_iter().collect::<GridMask>() => GridMask::from(GridRect::const_new::<1, 2, 5, 4>()));
+    test_ctor!(into_iter_ref: (&RECT_2_2_3_3).into_iter().collect::<Vec<_>>() => RECT_2_2_3_3.points().collect::<Vec<_>>());
+
+    test_self_method!(len_max: GridRect::MAX.points() => len() => 64);
+    test_self_method!(len_mixed: GridRect::const_new::<1, 2, 5, 4>().points() => len() => 20);
+
+    #[test]
+    fn for_loop() {
+        let rect = GridRect::const_new::<6, 6, 2, 2>();
+        let mut visited = Vec::new();
+        for point in &rect {
+            visited.push(point);
+        }
+        for point in rect {
+            assert!(visited.contains(&point));
+        }
+        assert_eq!(visited, rect.points().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn exact_len_both_ends() {
+        let mut iter = RECT_2_2_3_3.points();
+        assert_eq!(iter.next(), Some(GridPoint::const_new::<2, 2>()));
+        assert_eq!(iter.next_back(), Some(GridPoint::const_new::<4, 4>()));
+        assert_eq!(iter.len(), 7);
+        assert_eq!(iter.by_ref().count(), 7);
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+    }
+}
+
+mod area {
+    use super::*;
+
+    test_self_method!(max: GridRect::MAX => area() => 64);
+    test_self_method!(cell: GridRect::const_new::<4, 4, 1, 1>() => area() => 1);
+    test_self_method!(square: RECT_2_2_3_3 => area() => 9);
+    test_self_method!(row: GridRect::const_new::<0, 7, 8, 1>() => area() => 8);
+    test_self_method!(mixed: GridRect::const_new::<1, 2, 5, 4>() => area() => 20);
+}
+
+mod from_corners {
+    use super::*;
+
+    test_ctor!(max: GridRect::from_corners(GridPoint::ORIGIN, GridPoint::MAX) => GridRect::MAX);
+    test_ctor!(max_reversed: GridRect::from_corners(GridPoint::MAX, GridPoint::ORIGIN) => GridRect::MAX);
+    test_ctor!(same_point: GridRect::from_corners(CENTER, CENTER) => GridRect::const_new::<4, 4, 1, 1>());
+    test_ctor!(anti_corners: GridRect::from_corners(GridPoint::const_new::<4, 2>(), GridPoint::const_new::<2, 4>()) => RECT_2_2_3_3);
+    test_ctor!(row: GridRect::from_corners(GridPoint::const_new::<7, 3>(), GridPoint::const_new::<0, 3>()) => GridRect::const_new::<0, 3, 8, 1>());
+
+    #[test]
+    fn contains_both() {
+        GridPoint::all_values().for_each(|point| {
+            let rect = GridRect::from_corners(CENTER, point);
+            assert!(rect.contains_point(CENTER) && rect.contains_point(point));
+        });
+    }
+}
+
+mod split {
+    use super::*;
+
+    test_self_method!(row_half: GridRect::MAX => split_at_row(4) => Some((GridRect::const_new::<0, 0, 8, 4>(), GridRect::const_new::<0, 4, 8, 4>())));
+    test_self_method!(row_first: RECT_2_2_3_3 => split_at_row(1) => Some((GridRect::const_new::<2, 2, 3, 1>(), GridRect::const_new::<2, 3, 3, 2>())));
+    test_self_method!(row_last: RECT_2_2_3_3 => split_at_row(2) => Some((GridRect::const_new::<2, 2, 3, 2>(), GridRect::const_new::<2, 4, 3, 1>())));
+    test_self_method!(row_zero: RECT_2_2_3_3 => split_at_row(0) => None);
+    test_self_method!(row_height: RECT_2_2_3_3 => split_at_row(3) => None);
+    test_self_method!(row_beyond: RECT_2_2_3_3 => split_at_row(u8::MAX) => None);
+    test_self_method!(row_single: GridRect::const_new::<0, 0, 8, 1>() => split_at_row(1) => None);
+
+    test_self_method!(col_half: GridRect::MAX => split_at_col(4) => Some((GridRect::const_new::<0, 0, 4, 8>(), GridRect::const_new::<4, 0, 4, 8>())));
+    test_self_method!(col_first: RECT_2_2_3_3 => split_at_col(1) => Some((GridRect::const_new::<2, 2, 1, 3>(), GridRect::const_new::<3, 2, 2, 3>())));
+    test_self_method!(col_last: RECT_2_2_3_3 => split_at_col(2) => Some((GridRect::const_new::<2, 2, 2, 3>(), GridRect::const_new::<4, 2, 1, 3>())));
+    test_self_method!(col_zero: RECT_2_2_3_3 => split_at_col(0) => None);
+    test_self_method!(col_width: RECT_2_2_3_3 => split_at_col(3) => None);
+    test_self_method!(col_beyond: RECT_2_2_3_3 => split_at_col(u8::MAX) => None);
+    test_self_method!(col_single: GridRect::const_new::<0, 0, 1, 8>() => split_at_col(1) => None);
+
+    #[test]
+    fn partitions() {
+        let rect = GridRect::const_new::<1, 2, 5, 4>();
+        (1..4).for_each(|row| {
+            let (top, bottom) = rect.split_at_row(row).expect("row should split");
+            assert_eq!(GridMask::from(top) | GridMask::from(bottom), GridMask::from(rect));
+            assert!(!GridMask::from(top).overlaps(GridMask::from(bottom)));
+        });
+        (1..5).for_each(|col| {
+            let (left, right) = rect.split_at_col(col).expect("col should split");
+            assert_eq!(GridMask::from(left) | GridMask::from(right), GridMask::from(rect));
+            assert!(!GridMask::from(left).overlaps(GridMask::from(right)));
+        });
+    }
+}
+
+mod translate_clamped {
+    use grid_mask::GridVector;
+
+    use super::*;
+
+    test_self_method!(within: RECT_2_2_3_3 => translate_clamped(GridVector::new(1, -1)) => GridRect::const_new::<3, 1, 3, 3>());
+    test_self_method!(zero: RECT_2_2_3_3 => translate_clamped(GridVector::ZERO) => RECT_2_2_3_3);
+    test_self_method!(at_east_wall: GridRect::const_new::<6, 0, 2, 8>() => translate_clamped(GridVector::EAST) => GridRect::const_new::<6, 0, 2, 8>());
+    test_self_method!(clamp_east: RECT_2_2_3_3 => translate_clamped(GridVector::new(7, 0)) => GridRect::const_new::<5, 2, 3, 3>());
+    test_self_method!(clamp_west: RECT_2_2_3_3 => translate_clamped(GridVector::new(-7, 0)) => GridRect::const_new::<0, 2, 3, 3>());
+    test_self_method!(clamp_north: RECT_2_2_3_3 => translate_clamped(GridVector::new(0, i8::MIN)) => GridRect::const_new::<2, 0, 3, 3>());
+    test_self_method!(clamp_south: RECT_2_2_3_3 => translate_clamped(GridVector::new(0, i8::MAX)) => GridRect::const_new::<2, 5, 3, 3>());
+    test_self_method!(clamp_one_axis: RECT_2_2_3_3 => translate_clamped(GridVector::new(-10, 1)) => GridRect::const_new::<0, 3, 3, 3>());
+    test_self_method!(full: GridRect::MAX => translate_clamped(GridVector::SOUTH_EAST) => GridRect::MAX);
+}
@@ -1,5 +1,7 @@
 use grid_mask::GridSize;
 
+use crate::macros::test_self_method;
+
 #[test]
 fn test_const_new() {
     const S1: GridSize = GridSize::const_new::<1, 1>();
@@ -14,3 +16,35 @@ fn test_const_new() {
     assert_eq!(S3.width.get(), 1);
     assert_eq!(S3.height.get(), 8);
 }
+
+mod queries {
+    use super::*;
+
+    test_self_method!(area_min: GridSize::MIN => area() => 1);
+    test_self_method!(area_max: GridSize::MAX => area() => 64);
+    test_self_method!(area_rect: GridSize::const_new::<3, 5>() => area() => 15);
+
+    test_self_method!(square_min: GridSize::MIN => is_square() => true);
+    test_self_method!(square_4_4: GridSize::const_new::<4, 4>() => is_square() => true);
+    test_self_method!(square_wide: GridSize::const_new::<8, 1>() => is_square() => false);
+    test_self_method!(square_tall: GridSize::const_new::<1, 8>() => is_square() => false);
+
+    test_self_method!(max_dim_wide: GridSize::const_new::<7, 2>() => max_dim() => GridSize::const_new::<7, 2>().width);
+    test_self_method!(max_dim_tall: GridSize::const_new::<2, 7>() => max_dim() => GridSize::const_new::<2, 7>().height);
+    test_self_method!(max_dim_square: GridSize::MAX => max_dim() => GridSize::MAX.width);
+
+    test_self_method!(min_dim_wide: GridSize::const_new::<7, 2>() => min_dim() => GridSize::const_new::<7, 2>().height);
+    test_self_method!(min_dim_tall: GridSize::const_new::<2, 7>() => min_dim() => GridSize::const_new::<2, 7>().width);
+    test_self_method!(min_dim_square: GridSize::MIN => min_dim() => GridSize::MIN.width);
+
+    #[test]
+    fn const_eval() {
+        const SIZE: GridSize = GridSize::const_new::<3, 6>();
+        const AREA: u8 = SIZE.area();
+        const SQUARE: bool = SIZE.is_square();
+        const { assert!(!SQUARE) };
+        assert_eq!(AREA, 18);
+        assert_eq!(SIZE.max_dim().get(), 6);
+        assert_eq!(SIZE.min_dim().get(), 3);
+    }
+}
@@ -1,4 +1,4 @@
-use grid_mask::GridVector;
+use grid_mask::{ArrayVector, GridPoint, GridVector};
 
 #[test]
 fn test_new() {
@@ -55,3 +55,161 @@ fn test_sub_assign() {
     assert_eq!(v.x, 3);
     assert_eq!(v.y, 3);
 }
+
+#[test]
+fn test_add_directions() {
+    assert_eq!(GridVector::NORTH + GridVector::EAST, GridVector::NORTH_EAST);
+    assert_eq!(GridVector::SOUTH + GridVector::WEST, GridVector::SOUTH_WEST);
+    assert_eq!(GridVector::NORTH + GridVector::SOUTH, GridVector::ZERO);
+}
+
+#[test]
+fn test_neg() {
+    assert_eq!(-GridVector::SOUTH, GridVector::NORTH);
+    assert_eq!(-GridVector::NORTH_WEST, GridVector::SOUTH_EAST);
+    assert_eq!(-GridVector::ZERO, GridVector::ZERO);
+    assert_eq!(-GridVector::new(i8::MAX, -3), GridVector::new(-i8::MAX, 3));
+}
+
+#[test]
+fn test_mul() {
+    assert_eq!(GridVector::new(2, -3) * 2, GridVector::new(4, -6));
+    assert_eq!(GridVector::EAST * -4, GridVector::new(-4, 0));
+    assert_eq!(GridVector::new(2, -3) * 5, GridVector::new(2, -3).scale(5));
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "overflow")]
+fn test_neg_overflow() {
+    let _ = -GridVector::new(i8::MIN, 0);
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "overflow")]
+fn test_rotate_90_overflow() {
+    let _ = GridVector::new(0, i8::MIN).rotate_90();
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "overflow")]
+fn test_rotate_180_overflow() {
+    let _ = GridVector::new(i8::MIN, 0).rotate_180();
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "overflow")]
+fn test_rotate_270_overflow() {
+    let _ = GridVector::new(i8::MIN, 0).rotate_270();
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "overflow")]
+fn test_mul_overflow() {
+    let _ = GridVector::new(0, 64) * 2;
+}
+
+#[test]
+fn test_all_cardinal() {
+    assert_eq!(GridVector::ALL_CARDINAL, [GridVector::NORTH, GridVector::EAST, GridVector::SOUTH, GridVector::WEST]);
+    assert!(GridVector::ALL_CARDINAL.iter().all(|v| v.manhattan_distance() == 1));
+}
+
+#[test]
+fn test_all_octile() {
+    assert_eq!(
+        GridVector::ALL_OCTILE,
+        [
+            GridVector::NORTH,
+            GridVector::NORTH_EAST,
+            GridVector::EAST,
+            GridVector::SOUTH_EAST,
+            GridVector::SOUTH,
+            GridVector::SOUTH_WEST,
+            GridVector::WEST,
+            GridVector::NORTH_WEST,
+        ]
+    );
+    assert!(GridVector::ALL_CARDINAL.iter().all(|v| GridVector::ALL_OCTILE.contains(v)));
+    assert!(!GridVector::ALL_OCTILE.contains(&GridVector::ZERO));
+}
+
+#[test]
+fn test_magnitudes() {
+    assert_eq!(GridVector::ZERO.manhattan_magnitude(), 0);
+    assert_eq!(GridVector::ZERO.chebyshev_magnitude(), 0);
+    assert_eq!(GridVector::NORTH_WEST.manhattan_magnitude(), 2);
+    assert_eq!(GridVector::NORTH_WEST.chebyshev_magnitude(), 1);
+    assert_eq!(GridVector::new(-7, 7).manhattan_magnitude(), 14);
+    assert_eq!(GridVector::new(-7, 7).chebyshev_magnitude(), 7);
+    assert_eq!(GridVector::new(2, -5).chebyshev_magnitude(), 5);
+    assert_eq!(GridVector::new(i8::MIN, 0).chebyshev_magnitude(), 128);
+    assert_eq!(GridVector::new(i8::MIN, 0).manhattan_magnitude(), 128);
+    assert_eq!(GridVector::new(i8::MIN, i8::MIN).manhattan_magnitude(), u8::MAX);
+    assert_eq!(GridVector::new(i8::MAX, i8::MIN).manhattan_magnitude(), u8::MAX);
+}
+
+#[test]
+fn test_from_points() {
+    assert_eq!(GridVector::from_points(GridPoint::ORIGIN, GridPoint::MAX), GridVector::new(7, 7));
+    assert_eq!(GridVector::from_points(GridPoint::MAX, GridPoint::ORIGIN), GridVector::new(-7, -7));
+    assert_eq!(GridVector::from_points(GridPoint::MAX, GridPoint::MAX), GridVector::ZERO);
+
+    let (from, to) = (GridPoint::const_new::<5, 1>(), GridPoint::const_new::<2, 6>());
+    assert_eq!(GridVector::from_points(from, to), GridVector::new(-3, 5));
+    assert_eq!(GridVector::from_points(from, to), to - from);
+    assert_eq!(from.translate(GridVector::from_points(from, to)), Ok(to));
+}
+
+#[test]
+fn test_classification() {
+    assert!(GridVector::ALL_CARDINAL.iter().all(GridVector::is_cardinal));
+    assert!(GridVector::ALL_CARDINAL.iter().all(|v| !v.is_diagonal()));
+    assert!(GridVector::ALL_OCTILE.iter().all(GridVector::is_unit));
+    assert_eq!(GridVector::ALL_OCTILE.iter().filter(|v| v.is_diagonal()).count(), 4);
+
+    assert!(!GridVector::ZERO.is_cardinal());
+    assert!(!GridVector::ZERO.is_diagonal());
+    assert!(GridVector::ZERO.is_unit());
+
+    assert!(GridVector::new(-5, 0).is_cardinal());
+    assert!(!GridVector::new(-5, 0).is_unit());
+    assert!(GridVector::new(3, -2).is_diagonal());
+    assert!(!GridVector::new(1, i8::MIN).is_unit());
+}
+
+#[test]
+fn test_rotate() {
+    assert_eq!(GridVector::NORTH.rotate_90(), GridVector::EAST);
+    assert_eq!(GridVector::EAST.rotate_90(), GridVector::SOUTH);
+    assert_eq!(GridVector::SOUTH.rotate_90(), GridVector::WEST);
+    assert_eq!(GridVector::WEST.rotate_90(), GridVector::NORTH);
+    assert_eq!(GridVector::NORTH_EAST.rotate_90(), GridVector::SOUTH_EAST);
+
+    assert_eq!(GridVector::NORTH.rotate_180(), GridVector::SOUTH);
+    assert_eq!(GridVector::NORTH.rotate_270(), GridVector::WEST);
+    assert_eq!(GridVector::ZERO.rotate_90(), GridVector::ZERO);
+
+    // Rotating an octile direction by 90° skips two entries of the clockwise ordering.
+    GridVector::ALL_OCTILE.iter().enumerate().for_each(|(i, v)| {
+        assert_eq!(v.rotate_90(), GridVector::ALL_OCTILE[(i + 2) % 8]);
+        assert_eq!(v.rotate_180(), GridVector::ALL_OCTILE[(i + 4) % 8]);
+        assert_eq!(v.rotate_270(), GridVector::ALL_OCTILE[(i + 6) % 8]);
+    });
+
+    let v = GridVector::new(3, -5);
+    assert_eq!(v.rotate_90().rotate_90(), v.rotate_180());
+    assert_eq!(v.rotate_90().rotate_180(), v.rotate_270());
+    assert_eq!(v.rotate_270().rotate_90(), v);
+}
+
+#[test]
+fn test_into_array_vector() {
+    assert_eq!(ArrayVector::from(GridVector::NORTH_WEST), ArrayVector::new(-1, -1));
+    assert_eq!(ArrayVector::from(GridVector::new(i8::MIN, i8::MAX)), ArrayVector::new(-128, 127));
+    assert_eq!(ArrayVector::from(GridVector::ZERO), ArrayVector::ZERO);
+}
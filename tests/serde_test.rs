@@ -48,6 +48,20 @@ mod fail {
         assert!(res.is_err());
     }
 
+    #[test]
+    fn rect_oob() {
+        // x + w extends beyond the grid
+        let res = serde_json::from_str::<grid_mask::GridRect>(r#"{"x":4,"y":0,"w":5,"h":1}"#);
+        assert!(res.is_err());
+    }
+
+    #[test]
+    fn shape_discontiguous() {
+        // (0, 0) and (7, 7) are not connected
+        let res = serde_json::from_str::<grid_mask::GridShape>("[[0,0],[7,7]]");
+        assert!(res.is_err());
+    }
+
     #[test]
     fn mask_invalid_type() {
         // Mask expects list of points or u64
@@ -55,3 +69,86 @@ mod fail {
         assert!(res.is_err());
     }
 }
+
+mod rect {
+    use grid_mask::GridRect;
+
+    test_self_method!(ser: this = GridRect::const_new::<1, 2, 3, 4>() => serde_json::to_string(&this)? => r#"{"x":1,"y":2,"w":3,"h":4}"#);
+    test_ctor!(de: serde_json::from_str::<GridRect>(r#"{"x":1,"y":2,"w":3,"h":4}"#)? => GridRect::const_new::<1, 2, 3, 4>());
+}
+
+mod vector {
+    use grid_mask::GridVector;
+
+    test_self_method!(ser: this = GridVector::new(1, -2) => serde_json::to_string(&this)? => "[1,-2]");
+    test_ctor!(de: serde_json::from_str::<GridVector>("[1,-2]")? => GridVector::new(1, -2));
+}
+
+mod shape {
+    use super::*;
+    use grid_mask::{Cardinal, GridShape};
+
+    test_self_method!(ser: this = GridShape::<Cardinal>::try_from(GridMask::from(GridPoint::ORIGIN))? => serde_json::to_string(&this)? => "1");
+    test_self_method!(ser_full: this = GridShape::<Cardinal>::FULL => serde_json::to_string(&this)? => u64::MAX.to_string());
+    test_ctor!(de_bitmask: serde_json::from_str::<GridShape>("3")? => GridShape::<Cardinal>::try_from(GridMask(3))?);
+    test_ctor!(de_points: serde_json::from_str::<GridShape>("[[0,0],[1,0]]")? => GridShape::<Cardinal>::try_from(GridMask(3))?);
+}
+
+mod array_grid {
+    use std::str::FromStr;
+
+    type Grid8 = grid_mask::array_grid!(8, 8);
+    type Grid10 = grid_mask::array_grid!(10, 10);
+
+    test_self_method!(ser_8: this = Grid8::from([0x8142]) => serde_json::to_string(&this)? => "[33090]");
+    test_self_method!(ser_10: this = Grid10::FULL => serde_json::to_string(&this)? => format!("[{},{}]", u64::MAX, (1u64 << 36) - 1));
+    test_ctor!(de_8: serde_json::from_str::<Grid8>("[33090]")? => Grid8::from([0x8142]));
+    test_ctor!(de_10: serde_json::from_str::<Grid10>("[1,2]")? => Grid10::from([1, 2]));
+
+    // Bits beyond the end of the grid are cleared on deserialization.
+    test_ctor!(de_trailing: serde_json::from_str::<Grid10>(&format!("[{},{}]", u64::MAX, u64::MAX))? => Grid10::FULL);
+
+    #[test]
+    fn de_wrong_length() {
+        assert!(serde_json::from_str::<Grid10>("[1]").is_err());
+        assert!(serde_json::from_str::<Grid10>("[1,2,3]").is_err());
+        assert!(serde_json::from_str::<Grid8>("1").is_err());
+    }
+
+    #[test]
+    fn round_trip() -> Result<(), Box<dyn std::error::Error>> {
+        type Grid7x3 = grid_mask::array_grid!(7, 3);
+        type Grid20 = grid_mask::array_grid!(20, 20);
+
+        let grid8 = Grid8::from([0x8142_2418_1824_4281]);
+        let grid10 = Grid10::from_str(&"#.".repeat(50))?;
+        let grid7x3 = Grid7x3::FULL;
+        let grid20 = Grid20::from_fn(|x, y| (x + y) % 3 == 0);
+
+        assert_eq!(serde_json::from_str::<Grid8>(&serde_json::to_string(&grid8)?)?, grid8);
+        assert_eq!(serde_json::from_str::<Grid10>(&serde_json::to_string(&grid10)?)?, grid10);
+        assert_eq!(serde_json::from_str::<Grid7x3>(&serde_json::to_string(&grid7x3)?)?, grid7x3);
+        assert_eq!(serde_json::from_str::<Grid20>(&serde_json::to_string(&grid20)?)?, grid20);
+        Ok(())
+    }
+}
+
+mod round_trip {
+    use super::*;
+    use grid_mask::{Cardinal, GridRect, GridShape, GridVector, Octile};
+
+    fn round_trip<T>(value: &T) -> Result<T, serde_json::Error>
+    where
+        T: serde::Serialize + serde::de::DeserializeOwned,
+    {
+        serde_json::to_string(value).and_then(|json| serde_json::from_str(&json))
+    }
+
+    test_ctor!(mask: round_trip(&GridMask(0x8142_2418_1824_4281))? => GridMask(0x8142_2418_1824_4281));
+    test_ctor!(point: round_trip(&GridPoint::MAX)? => GridPoint::MAX);
+    test_ctor!(size: round_trip(&GridSize::MAX)? => GridSize::MAX);
+    test_ctor!(rect: round_trip(&GridRect::const_new::<7, 0, 1, 8>())? => GridRect::const_new::<7, 0, 1, 8>());
+    test_ctor!(vector: round_trip(&GridVector::NORTH_WEST)? => GridVector::NORTH_WEST);
+    test_ctor!(shape_cardinal: round_trip(&GridShape::<Cardinal>::FULL)? => GridShape::<Cardinal>::FULL);
+    test_ctor!(shape_octile: round_trip(&GridShape::<Octile>::try_from(GridMask(1 | 1 << 9))?)? => GridShape::<Octile>::try_from(GridMask(1 | 1 << 9))?);
+}